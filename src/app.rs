@@ -1,30 +1,62 @@
 use crate::config::app_config::AppConfig;
+use crate::config::config_sync::ConfigSync;
+use crate::config::config_watcher::ConfigWatcher;
 use crate::enums::tray_menu_event_enum::TrayMenuEventEnum;
 use crate::enums::ui_command_enum::UICommandEnum;
-use crate::error::app_error::Result;
-use crate::i18n::i18n_manager::I18nManager;
-use crate::monitor::monitor::{MonitorManager, SystemMonitor};
+use crate::error::app_error::{AppError, Result};
+use crate::error::lock_ext::LockExt;
+use crate::i18n::i18n_manager::{I18nManager, LanguageChoices};
+use crate::ipc::ipc_endpoint::IpcListener;
+use crate::ipc::ipc_server::IpcServer;
+use crate::ipc::stats_snapshot::StatsSnapshot;
+use crate::logging::logger::Logger;
+use crate::monitor::metric_history::MetricHistory;
+use crate::monitor::monitor::{MonitorManager, MonitorSample, SystemMonitor};
 use crate::tray::tray::{SystemTray, Tray};
-use crate::ui::ui;
+use crate::ui::dashboard_view::DashboardView;
+use crate::ui::process_view::ProcessView;
+use crate::ui::{ui, viewport};
 use auto_launch::AutoLaunch;
 use eframe::egui;
+use fluent::fluent_args;
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex, mpsc};
 use std::thread::{self, JoinHandle};
 use std::time::{Duration, Instant};
 use tray_icon::menu::{MenuEvent, MenuId};
 
+const PROCESS_VIEW_VIEWPORT_ID: &str = "process_view";
+const DASHBOARD_VIEWPORT_ID: &str = "dashboard";
+/// How long settings must stay unchanged before they are written to disk.
+const CONFIG_SAVE_DELAY: Duration = Duration::from_millis(500);
+const CONFIG_SAVE_RETRY_DELAY: Duration = Duration::from_secs(5);
+
 pub struct App {
     app_config: Arc<Mutex<AppConfig>>,
     i18n: Arc<Mutex<I18nManager>>,
+    language_choices: LanguageChoices,
     monitor_manager: Box<dyn MonitorManager>,
     tray_manager: Option<Box<dyn Tray>>,
     last_update: Instant,
-    ui_command_rx: Option<mpsc::Receiver<UICommandEnum>>,
+    /// Commands from the tray menu and the IPC endpoint.
+    ui_command_tx: mpsc::Sender<UICommandEnum>,
+    ui_command_rx: mpsc::Receiver<UICommandEnum>,
+    ipc_server: Option<IpcServer>,
     tray_thread_handle: Option<JoinHandle<()>>,
     tray_shutdown_tx: Option<mpsc::Sender<()>>,
     is_shutting_down: bool,
     auto_launch: AutoLaunch,
+    logger: Logger,
+    process_view: ProcessView,
+    show_process_view: bool,
+    history: MetricHistory,
+    last_stats: Vec<MonitorSample>,
+    dashboard_view: DashboardView,
+    show_dashboard: bool,
+    config_watcher: ConfigWatcher,
+    config_sync: ConfigSync,
+    config_reload_error: Option<String>,
+    config_save_error: Option<String>,
 }
 
 impl App {
@@ -33,31 +65,70 @@ impl App {
         app_config: AppConfig,
         i18n: Arc<Mutex<I18nManager>>,
         auto_launch: AutoLaunch,
+        logger: Logger,
+        ipc_listener: Option<IpcListener>,
+        system_monitor: SystemMonitor,
     ) -> Result<Self> {
-        if app_config.general.run_on_startup {
-            auto_launch.enable().ok();
-        } else {
-            auto_launch.disable().ok();
-        }
-
         if app_config.general.minimized_window_on_startup {
             cc.egui_ctx
                 .send_viewport_cmd(egui::ViewportCommand::Minimized(true));
         }
 
-        let (_tx, rx) = mpsc::channel();
-        Ok(Self {
+        let config_watcher = {
+            let ctx = cc.egui_ctx.clone();
+            ConfigWatcher::spawn(app_config.path.clone(), move || ctx.request_repaint())
+        };
+
+        let (ui_command_tx, ui_command_rx) = mpsc::channel();
+        let ipc_server = ipc_listener.and_then(|listener| {
+            let ctx = cc.egui_ctx.clone();
+            IpcServer::spawn(listener, ui_command_tx.clone(), move || {
+                ctx.request_repaint()
+            })
+            .inspect_err(|e| tracing::warn!(error = %e, "Failed to start the IPC server"))
+            .ok()
+        });
+
+        let app = Self {
+            config_sync: ConfigSync::new(app_config.clone()),
             app_config: Arc::new(Mutex::new(app_config)),
             i18n,
-            monitor_manager: Box::new(SystemMonitor::new()),
+            language_choices: LanguageChoices::load(),
+            monitor_manager: Box::new(system_monitor),
             tray_manager: None,
             last_update: Instant::now(),
-            ui_command_rx: Some(rx),
+            ui_command_tx,
+            ui_command_rx,
+            ipc_server,
             tray_thread_handle: None,
             tray_shutdown_tx: None,
             is_shutting_down: false,
             auto_launch,
-        })
+            logger,
+            process_view: ProcessView::new(),
+            show_process_view: false,
+            history: MetricHistory::new(MetricHistory::DEFAULT_RETENTION),
+            last_stats: Vec::new(),
+            dashboard_view: DashboardView::new(),
+            show_dashboard: false,
+            config_watcher,
+            config_reload_error: None,
+            config_save_error: None,
+        };
+        app.apply_autostart(app.config_sync.saved().general.run_on_startup);
+        Ok(app)
+    }
+
+    fn start_tray(&mut self, ctx: &egui::Context) -> Result<()> {
+        let (tray_manager, menu_rx, id_map) = {
+            let app_config = self.app_config.lock_or_recover();
+            let i18n_guard = self.i18n.lock_or_recover();
+            SystemTray::new(&app_config, &i18n_guard)?
+        };
+
+        self.spawn_tray_handler_thread(ctx, menu_rx, id_map)?;
+        self.tray_manager = Some(Box::new(tray_manager));
+        Ok(())
     }
 
     fn spawn_tray_handler_thread(
@@ -65,8 +136,8 @@ impl App {
         ctx: &egui::Context,
         menu_rx: crossbeam_channel::Receiver<MenuEvent>,
         id_map: HashMap<MenuId, TrayMenuEventEnum>,
-    ) -> mpsc::Receiver<UICommandEnum> {
-        let (ui_tx, ui_rx) = mpsc::channel();
+    ) -> Result<()> {
+        let ui_tx = self.ui_command_tx.clone();
         let (shutdown_tx, shutdown_rx) = mpsc::channel();
         let ctx_clone = ctx.clone();
         let app_config_clone = self.app_config.clone();
@@ -83,11 +154,14 @@ impl App {
                     app_config_clone,
                 );
             })
-            .expect("Failed to spawn tray handler thread");
+            .map_err(|source| AppError::ThreadSpawn {
+                name: "tray-handler".to_string(),
+                source,
+            })?;
 
         self.tray_thread_handle = Some(handle);
         self.tray_shutdown_tx = Some(shutdown_tx);
-        ui_rx
+        Ok(())
     }
 
     fn shutdown_tray_handler_thread(&mut self) {
@@ -95,13 +169,11 @@ impl App {
             let _ = shutdown_tx.send(());
         }
 
-        if let Some(handle) = self.tray_thread_handle.take() {
-            if let Err(e) = handle.join() {
-                eprintln!("Tray thread panicked during shutdown: {:?}", e);
-            }
+        if let Some(handle) = self.tray_thread_handle.take()
+            && let Err(e) = handle.join()
+        {
+            tracing::error!(panic = ?e, "Tray thread panicked during shutdown");
         }
-
-        self.ui_command_rx.take();
     }
 
     fn tray_event_loop(
@@ -121,6 +193,17 @@ impl App {
                 if let Some(action) = id_map.get(&event.id) {
                     let command = match action {
                         TrayMenuEventEnum::Settings => UICommandEnum::ShowSettings,
+                        TrayMenuEventEnum::Dashboard => UICommandEnum::ShowDashboard,
+                        TrayMenuEventEnum::ToggleMonitor(monitor_type) => {
+                            let is_active = app_config
+                                .lock_or_recover()
+                                .is_monitor_active(*monitor_type);
+                            UICommandEnum::SetMonitorActive(*monitor_type, !is_active)
+                        }
+                        TrayMenuEventEnum::TogglePause => {
+                            let is_paused = app_config.lock_or_recover().general.sampling_paused;
+                            UICommandEnum::SetPaused(!is_paused)
+                        }
                         TrayMenuEventEnum::Quit => UICommandEnum::Quit,
                     };
 
@@ -133,7 +216,10 @@ impl App {
                     }
                 }
             } else {
-                let delay = app_config.lock().unwrap().timing.tray_error_retry_delay_ms;
+                let delay = app_config
+                    .lock_or_recover()
+                    .timing
+                    .tray_error_retry_delay_ms;
                 thread::sleep(Duration::from_millis(delay.min(50)));
             }
         }
@@ -142,11 +228,7 @@ impl App {
     fn process_ui_commands(&mut self, ctx: &egui::Context) -> bool {
         let mut wants_to_quit = false;
 
-        let commands: Vec<UICommandEnum> = if let Some(rx) = &self.ui_command_rx {
-            rx.try_iter().collect()
-        } else {
-            Vec::new()
-        };
+        let commands: Vec<UICommandEnum> = self.ui_command_rx.try_iter().collect();
 
         for command in commands {
             match command {
@@ -154,6 +236,40 @@ impl App {
                     ctx.send_viewport_cmd(egui::ViewportCommand::Minimized(false));
                     ctx.send_viewport_cmd(egui::ViewportCommand::Focus);
                 }
+                UICommandEnum::ShowDashboard => {
+                    self.show_dashboard = true;
+                    ctx.send_viewport_cmd_to(
+                        egui::ViewportId::from_hash_of(DASHBOARD_VIEWPORT_ID),
+                        egui::ViewportCommand::Focus,
+                    );
+                }
+                UICommandEnum::SetMonitorActive(monitor_type, active) => {
+                    // IPC clients address monitors by stable ids such as "group-3", which
+                    // may name a group or pinned process that isn't configured.
+                    let exists = self
+                        .app_config
+                        .lock_or_recover()
+                        .monitor_types()
+                        .contains(&monitor_type);
+                    if exists {
+                        self.update_config(|app_config| {
+                            app_config.set_monitor_active(monitor_type, active)
+                        });
+                    } else {
+                        tracing::warn!(monitor = monitor_type.id(), "Ignoring unknown monitor");
+                    }
+                }
+                UICommandEnum::SetPaused(paused) => {
+                    self.update_config(|app_config| app_config.general.sampling_paused = paused);
+                }
+                UICommandEnum::GetStats(reply_tx) => {
+                    let snapshot = {
+                        let app_config = self.app_config.lock_or_recover();
+                        let i18n_guard = self.i18n.lock_or_recover();
+                        StatsSnapshot::new(&app_config, &i18n_guard, &self.last_stats)
+                    };
+                    let _ = reply_tx.send(snapshot.to_json());
+                }
                 UICommandEnum::Quit => {
                     self.initiate_shutdown();
                     wants_to_quit = true;
@@ -164,16 +280,267 @@ impl App {
         wants_to_quit
     }
 
+    /// Applies a change made outside the settings window and saves it right away.
+    /// While an external edit waits for the user, saving is left to the choice
+    /// they make, so the file on disk isn't overwritten behind their back.
+    fn update_config(&mut self, change: impl FnOnce(&mut AppConfig)) {
+        change(&mut self.app_config.lock_or_recover());
+        if self.config_sync.may_save() {
+            self.save_config();
+        }
+
+        if let Some(tray_manager) = &self.tray_manager {
+            let app_config = self.app_config.lock_or_recover();
+            let i18n_guard = self.i18n.lock_or_recover();
+            if let Err(e) =
+                tray_manager.update(&app_config, &i18n_guard, &self.last_stats, &self.history)
+            {
+                tracing::warn!(error = %e, "Error updating tray icon");
+            }
+        }
+    }
+
+    fn save_config(&mut self) {
+        let app_config = self.app_config.lock_or_recover();
+        match app_config.save() {
+            Ok(()) => {
+                self.config_sync.mark_saved(app_config.clone());
+                self.config_save_error = None;
+            }
+            Err(e) => {
+                tracing::error!(error = %e, "Error saving app config");
+                self.config_save_error = Some(e.to_string());
+            }
+        }
+    }
+
+    /// Saves settings edited in the UI once they have stopped changing, so
+    /// dragging a slider doesn't write the file on every frame.
+    fn save_config_when_idle(&mut self) {
+        let app_config = self.app_config.lock_or_recover().clone();
+        // A failed save is retried after `CONFIG_SAVE_RETRY_DELAY`.
+        let delay = if self.config_save_error.is_some() {
+            CONFIG_SAVE_RETRY_DELAY
+        } else {
+            CONFIG_SAVE_DELAY
+        };
+
+        if self
+            .config_sync
+            .is_save_due(&app_config, delay, Instant::now())
+        {
+            self.save_config();
+        }
+    }
+
+    /// Picks up edits made to the config file outside the app. Invalid files are
+    /// ignored, and edits that clash with unsaved settings wait for the user.
+    fn reload_config(&mut self, ctx: &egui::Context) {
+        let path = self.config_sync.saved().path.clone();
+        if !path.exists() {
+            return;
+        }
+        self.language_choices = LanguageChoices::load();
+
+        let loaded = match AppConfig::new(&path) {
+            Ok(loaded) => loaded,
+            Err(e) => {
+                tracing::warn!(path = %path.display(), error = %e, "Ignoring invalid config change");
+                self.config_reload_error = Some(e.to_string());
+                return;
+            }
+        };
+        self.config_reload_error = None;
+
+        let current = self.app_config.lock_or_recover().clone();
+        if let Some(loaded) = self.config_sync.external_change(&current, loaded) {
+            self.apply_external_config(ctx, loaded);
+        }
+    }
+
+    fn apply_external_config(&mut self, ctx: &egui::Context, loaded: AppConfig) {
+        let previous = std::mem::replace(&mut *self.app_config.lock_or_recover(), loaded.clone());
+
+        if previous.general.language != loaded.general.language {
+            match I18nManager::new(&loaded.general.language) {
+                Ok(i18n_manager) => *self.i18n.lock_or_recover() = i18n_manager,
+                Err(e) => tracing::warn!(error = %e, "Failed to switch language"),
+            }
+            self.restart_tray(ctx);
+        } else if let Some(tray_manager) = &self.tray_manager {
+            tray_manager.sync_menu_state(&loaded);
+        }
+
+        if previous.general.run_on_startup != loaded.general.run_on_startup {
+            self.apply_autostart(loaded.general.run_on_startup);
+        }
+
+        if previous.logging.level != loaded.logging.level {
+            self.logger.set_level(loaded.logging.level);
+        }
+
+        self.config_sync.mark_saved(loaded);
+    }
+
+    fn draw_config_notices(&mut self, ctx: &egui::Context) {
+        if !self.config_sync.has_pending_external()
+            && self.config_reload_error.is_none()
+            && self.config_save_error.is_none()
+        {
+            return;
+        }
+
+        let mut reload_external = None;
+        let mut dismiss_error = false;
+
+        egui::TopBottomPanel::top("config_notices").show(ctx, |ui| {
+            let i18n_guard = self.i18n.lock_or_recover();
+            ui.add_space(5.0);
+
+            if self.config_sync.has_pending_external() {
+                ui.colored_label(
+                    ui.visuals().warn_fg_color,
+                    i18n_guard.get_message("config-conflict-message"),
+                );
+                ui.horizontal(|ui| {
+                    if ui
+                        .button(i18n_guard.get_message("config-conflict-reload-button"))
+                        .clicked()
+                    {
+                        reload_external = Some(true);
+                    }
+                    if ui
+                        .button(i18n_guard.get_message("config-conflict-keep-button"))
+                        .clicked()
+                    {
+                        reload_external = Some(false);
+                    }
+                });
+            }
+
+            if let Some(error) = &self.config_reload_error {
+                ui.horizontal(|ui| {
+                    ui.colored_label(
+                        ui.visuals().error_fg_color,
+                        i18n_guard.get_message_with_args(
+                            "config-reload-error",
+                            &fluent_args!["details" => error.as_str()],
+                        ),
+                    );
+                    dismiss_error = ui.small_button("✖").clicked();
+                });
+            }
+
+            if let Some(error) = &self.config_save_error {
+                ui.colored_label(
+                    ui.visuals().error_fg_color,
+                    i18n_guard.get_message_with_args(
+                        "config-save-error",
+                        &fluent_args!["details" => error.as_str()],
+                    ),
+                );
+            }
+
+            ui.add_space(5.0);
+        });
+
+        if let Some(reload) = reload_external {
+            match self.config_sync.resolve_pending_external(reload) {
+                Some(loaded) => self.apply_external_config(ctx, loaded),
+                None => self.save_config(),
+            }
+        }
+
+        if dismiss_error {
+            self.config_reload_error = None;
+        }
+    }
+
+    fn restart_tray(&mut self, ctx: &egui::Context) {
+        self.shutdown_tray_handler_thread();
+        self.tray_manager = None;
+        ctx.request_repaint();
+    }
+
+    fn apply_autostart(&self, enabled: bool) {
+        let result = if enabled {
+            self.auto_launch.enable()
+        } else {
+            self.auto_launch.disable()
+        };
+
+        if let Err(e) = result {
+            tracing::warn!(error = %e, "Failed to update autostart");
+        }
+    }
+
     fn update_tray_icons(&mut self, app_config: &AppConfig) {
         if let Some(tray_manager) = &self.tray_manager {
-            let stats = self.monitor_manager.update_all(app_config);
-            let i18n_guard = self.i18n.lock().unwrap();
-            if let Err(e) = tray_manager.update(&app_config.active_monitors, &i18n_guard, &stats) {
-                eprintln!("Error updating tray icon: {}", e);
+            // While paused, the last samples are kept so icons and menu state still follow
+            // the config. Monitors that have none yet, e.g. at startup or when turned on
+            // while paused, are still sampled once so their icons aren't blank.
+            if !app_config.general.sampling_paused || self.has_unsampled_monitor(app_config) {
+                self.last_stats = self.monitor_manager.update_all(app_config);
+                self.history.record(&self.last_stats, Instant::now());
+            }
+
+            let i18n_guard = self.i18n.lock_or_recover();
+            if let Err(e) =
+                tray_manager.update(app_config, &i18n_guard, &self.last_stats, &self.history)
+            {
+                tracing::warn!(error = %e, "Error updating tray icon");
             }
         }
     }
 
+    fn has_unsampled_monitor(&self, app_config: &AppConfig) -> bool {
+        app_config.monitor_types().into_iter().any(|monitor_type| {
+            app_config.is_monitor_active(monitor_type)
+                && !self
+                    .last_stats
+                    .iter()
+                    .any(|sample| sample.monitor_type == monitor_type)
+        })
+    }
+
+    fn show_process_view_viewport(&mut self, ctx: &egui::Context) {
+        let i18n_guard = self.i18n.lock_or_recover();
+        let mut app_config = self.app_config.lock_or_recover();
+        let title = i18n_guard.get_message("processes-window-title");
+        let process_view = &mut self.process_view;
+
+        let close_requested = viewport::show_child_viewport(
+            ctx,
+            PROCESS_VIEW_VIEWPORT_ID,
+            &title,
+            ProcessView::DEFAULT_SIZE,
+            |ui| process_view.draw(ui, &i18n_guard, &mut app_config),
+        );
+
+        if close_requested {
+            self.show_process_view = false;
+        }
+    }
+
+    fn show_dashboard_viewport(&mut self, ctx: &egui::Context, app_config: &AppConfig) {
+        let i18n_guard = self.i18n.lock_or_recover();
+        let title = i18n_guard.get_message("dashboard-window-title");
+        let dashboard_view = &mut self.dashboard_view;
+        let history = &self.history;
+
+        let close_requested = viewport::show_child_viewport(
+            ctx,
+            DASHBOARD_VIEWPORT_ID,
+            &title,
+            DashboardView::DEFAULT_SIZE,
+            |ui| dashboard_view.draw(ui, &i18n_guard, app_config, history),
+        );
+
+        if close_requested {
+            self.show_dashboard = false;
+        }
+    }
+
     fn initiate_shutdown(&mut self) {
         if self.is_shutting_down {
             return;
@@ -186,46 +553,40 @@ impl App {
     }
 
     fn cleanup_resources(&mut self) {
-        if let Err(e) = self.app_config.lock().unwrap().save() {
-            eprintln!("Error saving app config on exit: {}", e);
+        // Only write when something changed, so edits made to the file while the
+        // app was running aren't overwritten with the in-memory copy.
+        let has_unsaved_changes = self
+            .config_sync
+            .has_unsaved_changes(&self.app_config.lock_or_recover());
+        if has_unsaved_changes && self.config_sync.may_save() {
+            self.save_config();
         }
 
         if !self.is_shutting_down {
             self.initiate_shutdown();
         }
 
-        if let Some(handle) = self.tray_thread_handle.take() {
-            if let Err(e) = handle.join() {
-                eprintln!("Tray thread panicked during shutdown: {:?}", e);
-            }
+        if let Some(handle) = self.tray_thread_handle.take()
+            && let Err(e) = handle.join()
+        {
+            tracing::error!(panic = ?e, "Tray thread panicked during shutdown");
         }
 
         self.tray_shutdown_tx.take();
-        self.ui_command_rx.take();
+        self.ipc_server.take();
     }
 }
 
 impl eframe::App for App {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
-        if self.tray_manager.is_none() && !self.is_shutting_down {
-            let tray_result = {
-                let i18n_guard = self.i18n.lock().unwrap();
-                SystemTray::new(&i18n_guard)
-            };
-
-            match tray_result {
-                Ok((tray_manager, menu_rx, id_map)) => {
-                    self.tray_manager = Some(Box::new(tray_manager));
-                    let rx = self.spawn_tray_handler_thread(ctx, menu_rx, id_map);
-                    self.ui_command_rx = Some(rx);
-                }
-                Err(e) => {
-                    eprintln!("Failed to initialize tray manager: {}", e);
-                    self.initiate_shutdown();
-                    ctx.send_viewport_cmd(egui::ViewportCommand::Close);
-                    return;
-                }
-            }
+        if self.tray_manager.is_none()
+            && !self.is_shutting_down
+            && let Err(e) = self.start_tray(ctx)
+        {
+            tracing::error!(error = %e, "Failed to initialize tray manager");
+            self.initiate_shutdown();
+            ctx.send_viewport_cmd(egui::ViewportCommand::Close);
+            return;
         }
 
         if self.is_shutting_down {
@@ -238,44 +599,84 @@ impl eframe::App for App {
             return;
         }
 
-        let app_config_snapshot = { self.app_config.lock().unwrap().clone() };
+        if self.config_watcher.has_changed() {
+            self.reload_config(ctx);
+        }
+
+        let app_config_snapshot = { self.app_config.lock_or_recover().clone() };
+
+        if self
+            .tray_manager
+            .as_ref()
+            .is_some_and(|tray| tray.monitor_types() != app_config_snapshot.monitor_types())
+        {
+            self.shutdown_tray_handler_thread();
+            self.tray_manager = None;
+            self.last_stats.clear();
+            let monitor_types = app_config_snapshot.monitor_types();
+            self.history
+                .retain(|monitor_type| monitor_types.contains(&monitor_type));
+            ctx.request_repaint();
+            return;
+        }
 
         if self.last_update.elapsed()
             >= Duration::from_secs(app_config_snapshot.refresh.default_refresh_seconds)
+            || self.has_unsampled_monitor(&app_config_snapshot)
         {
             self.update_tray_icons(&app_config_snapshot);
             self.last_update = Instant::now();
         }
 
+        self.draw_config_notices(ctx);
+
         egui::CentralPanel::default().show(ctx, |ui| {
-            let (shutdown_requested, language_changed, autostart_changed) =
-                ui::draw_ui(ui, self.app_config.clone(), self.i18n.clone());
+            let result = ui::draw_ui(
+                ui,
+                self.app_config.clone(),
+                self.i18n.clone(),
+                &self.language_choices,
+            );
+
+            if result.language_changed {
+                self.restart_tray(ctx);
+            }
 
-            if language_changed {
-                self.shutdown_tray_handler_thread();
-                self.tray_manager = None;
-                ctx.request_repaint();
+            if result.autostart_changed {
+                let run_on_startup = self.app_config.lock_or_recover().general.run_on_startup;
+                self.apply_autostart(run_on_startup);
             }
 
-            if autostart_changed {
-                let app_config = self.app_config.lock().unwrap();
-                if app_config.general.run_on_startup {
-                    if let Err(e) = self.auto_launch.enable() {
-                        eprintln!("Failed to enable autostart: {}", e);
-                    }
-                } else {
-                    if let Err(e) = self.auto_launch.disable() {
-                        eprintln!("Failed to disable autostart: {}", e);
-                    }
-                }
+            if result.log_level_changed {
+                let level = self.app_config.lock_or_recover().logging.level;
+                self.logger.set_level(level);
             }
 
-            if shutdown_requested {
+            if result.view_logs_requested {
+                self.logger.open_log_dir();
+            }
+
+            if result.process_view_requested && !self.show_process_view {
+                self.process_view.reset();
+                self.show_process_view = true;
+            }
+
+            if result.shutdown_requested {
                 self.initiate_shutdown();
                 ctx.send_viewport_cmd(egui::ViewportCommand::Close);
             }
         });
 
+        if self.show_process_view {
+            self.show_process_view_viewport(ctx);
+        }
+
+        if self.show_dashboard {
+            self.show_dashboard_viewport(ctx, &app_config_snapshot);
+        }
+
+        self.save_config_when_idle();
+
         ctx.request_repaint_after(Duration::from_millis(
             app_config_snapshot.timing.ui_repaint_interval.min(100),
         ));
@@ -1,57 +1,596 @@
+use crate::config::{app_paths, config_migration, config_validation};
 use crate::enums::{
-    monitor_type_enum::MonitorTypeEnum, supported_language_enum::SupportedLanguageEnum,
+    language_selection_enum::LanguageSelectionEnum, log_level_enum::LogLevelEnum,
+    monitor_type_enum::MonitorTypeEnum, process_filter_enum::ProcessFilterEnum,
+    process_match_rule_enum::ProcessMatchRuleEnum, process_metric_enum::ProcessMetricEnum,
 };
+use crate::error::app_error::{AppError, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
+use std::io::Write;
+use std::path::{Path, PathBuf};
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(default)]
 pub struct GeneralConfig {
     pub minimized_window_on_startup: bool,
     pub run_on_startup: bool,
-    pub language: SupportedLanguageEnum,
+    pub language: LanguageSelectionEnum,
+    pub sampling_paused: bool,
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
+impl Default for GeneralConfig {
+    fn default() -> Self {
+        Self {
+            minimized_window_on_startup: false,
+            run_on_startup: false,
+            language: LanguageSelectionEnum::SystemDefault,
+            sampling_paused: false,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(default)]
 pub struct TimingConfig {
     pub tray_error_retry_delay_ms: u64,
     pub ui_repaint_interval: u64,
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
+impl Default for TimingConfig {
+    fn default() -> Self {
+        Self {
+            tray_error_retry_delay_ms: 100,
+            ui_repaint_interval: 100,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(default)]
 pub struct RefreshConfig {
     pub default_refresh_seconds: u64,
     pub min_refresh_seconds: u64,
     pub max_refresh_seconds: u64,
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
+impl Default for RefreshConfig {
+    fn default() -> Self {
+        Self {
+            default_refresh_seconds: 4,
+            min_refresh_seconds: 1,
+            max_refresh_seconds: 60,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct TooltipConfig {
+    pub statistics_window_minutes: u64,
+}
+
+impl Default for TooltipConfig {
+    fn default() -> Self {
+        Self {
+            statistics_window_minutes: 5,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(default)]
 pub struct WindowConfig {
     pub settings_width: f32,
     pub settings_height: f32,
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
+impl Default for WindowConfig {
+    fn default() -> Self {
+        Self {
+            settings_width: 500.0,
+            settings_height: 350.0,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct LoggingConfig {
+    /// Ignored while `RUST_LOG` is set.
+    pub level: LogLevelEnum,
+}
+
+impl Default for LoggingConfig {
+    fn default() -> Self {
+        Self {
+            level: LogLevelEnum::Info,
+        }
+    }
+}
+
+/// Short icon label derived from a process name or pattern.
+pub fn default_process_label(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric())
+        .take(3)
+        .collect::<String>()
+        .to_uppercase()
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct PinnedProcessConfig {
+    /// Identifies the monitor across edits of the list, see `AppConfig::assign_monitor_ids`.
+    #[serde(default)]
+    pub id: u32,
+    #[serde(default)]
+    pub label: String,
+    pub rule: ProcessMatchRuleEnum,
+    #[serde(default)]
+    pub metric: ProcessMetricEnum,
+    #[serde(default = "enabled_by_default")]
+    pub enabled: bool,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct ProcessGroupConfig {
+    /// Identifies the monitor across edits of the list, see `AppConfig::assign_monitor_ids`.
+    #[serde(default)]
+    pub id: u32,
+    #[serde(default)]
+    pub label: String,
+    pub filter: ProcessFilterEnum,
+    #[serde(default)]
+    pub metric: ProcessMetricEnum,
+    #[serde(default = "enabled_by_default")]
+    pub enabled: bool,
+}
+
+fn enabled_by_default() -> bool {
+    true
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(default)]
 pub struct AppConfig {
+    /// Schema version, see `config_migration`.
+    pub config_version: u32,
     pub app_name: String,
     pub active_monitors: HashSet<MonitorTypeEnum>,
+    pub pinned_processes: Vec<PinnedProcessConfig>,
+    pub process_groups: Vec<ProcessGroupConfig>,
     pub general: GeneralConfig,
     pub refresh: RefreshConfig,
     pub timing: TimingConfig,
+    pub tooltip: TooltipConfig,
     pub window: WindowConfig,
+    pub logging: LoggingConfig,
+    /// File the config was loaded from and is saved back to.
+    #[serde(skip)]
+    pub path: PathBuf,
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self {
+            config_version: config_migration::CURRENT_CONFIG_VERSION,
+            app_name: "Rust System Monitor".to_string(),
+            active_monitors: HashSet::from([MonitorTypeEnum::CpuUsage, MonitorTypeEnum::RamUsage]),
+            pinned_processes: Vec::new(),
+            process_groups: Vec::new(),
+            general: GeneralConfig::default(),
+            refresh: RefreshConfig::default(),
+            timing: TimingConfig::default(),
+            tooltip: TooltipConfig::default(),
+            window: WindowConfig::default(),
+            logging: LoggingConfig::default(),
+            path: PathBuf::new(),
+        }
+    }
 }
 
 impl AppConfig {
-    pub fn new() -> Result<Self, config::ConfigError> {
+    /// Loads the config from `path`. Missing fields take their default values.
+    /// When the file doesn't exist yet, the defaults are written there.
+    pub fn new(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            let app_config = Self {
+                path: path.to_path_buf(),
+                ..Self::default()
+            };
+            if let Err(e) = app_config.save() {
+                tracing::warn!(path = %path.display(), error = %e, "Failed to write default config");
+            }
+            return Ok(app_config);
+        }
+
+        let document = Self::load_migrated_document(path)?;
         let config_builder = config::Config::builder()
-            .add_source(config::File::with_name("config.toml"))
+            .add_source(config::File::from_str(&document, config::FileFormat::Toml))
             .add_source(config::Environment::with_prefix("APP"));
 
-        config_builder.build()?.try_deserialize()
+        let mut app_config: Self = config_builder.build()?.try_deserialize()?;
+        let ids_assigned = app_config.assign_monitor_ids();
+        let issues = config_validation::validate(&app_config);
+        if !issues.is_empty() {
+            return Err(AppError::InvalidConfig(issues));
+        }
+
+        app_config.path = path.to_path_buf();
+        // Written back right away so the ids survive later edits of the file.
+        if ids_assigned && let Err(e) = app_config.save() {
+            tracing::warn!(path = %path.display(), error = %e, "Failed to save assigned monitor ids");
+        }
+        Ok(app_config)
+    }
+
+    /// Copies the config of an older version, which was kept in the working
+    /// directory, to `path` unless a config already exists there. Loading it
+    /// then migrates it like any other file.
+    pub fn import_legacy(path: &Path) {
+        Self::import_from(path, &app_paths::legacy_config_files());
     }
 
-    pub fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
+    /// Imports the first of `legacy_files` that exists. Returns the one imported.
+    fn import_from(path: &Path, legacy_files: &[PathBuf]) -> Option<PathBuf> {
+        if path.exists() {
+            return None;
+        }
+        let legacy_file = legacy_files.iter().find(|file| file.is_file())?;
+
+        let copied = match path.parent() {
+            Some(parent) => std::fs::create_dir_all(parent),
+            None => Ok(()),
+        }
+        .and_then(|()| std::fs::copy(legacy_file, path));
+        match copied {
+            Ok(_) => {
+                tracing::info!(from = %legacy_file.display(), to = %path.display(), "Imported config");
+                Some(legacy_file.clone())
+            }
+            Err(e) => {
+                tracing::warn!(from = %legacy_file.display(), error = %e, "Failed to import config");
+                None
+            }
+        }
+    }
+
+    /// Reads the TOML document at `path` and upgrades it to the current schema.
+    /// A migrated file is written back after a copy of the original is saved
+    /// next to it. Fails when that copy can't be made, as any later save would
+    /// replace the original.
+    fn load_migrated_document(path: &Path) -> Result<String> {
+        let contents = std::fs::read_to_string(path).map_err(|e| AppError::io(path, e))?;
+        let mut document: toml::Table = toml::from_str(&contents)?;
+
+        let Some(from_version) = config_migration::migrate(&mut document) else {
+            return Ok(contents);
+        };
+
+        let migrated = toml::to_string_pretty(&document)?;
+        let backup_path = config_migration::backup_path(path, from_version);
+        std::fs::copy(path, &backup_path).map_err(|e| AppError::io(&backup_path, e))?;
+        match write_atomically(path, &migrated) {
+            Ok(()) => tracing::info!(
+                from_version,
+                to_version = config_migration::CURRENT_CONFIG_VERSION,
+                backup = %backup_path.display(),
+                "Migrated config"
+            ),
+            Err(e) => tracing::error!(error = %e, "Failed to write migrated config"),
+        }
+
+        Ok(migrated)
+    }
+
+    /// Gives process monitor entries without an id, or with one that is
+    /// already taken, an unused one. Files written before ids existed and hand
+    /// edits may contain such entries. Returns whether any id was assigned.
+    fn assign_monitor_ids(&mut self) -> bool {
+        let mut next_id = self.next_monitor_id();
+        let first_new_id = next_id;
+        let mut used_ids = HashSet::new();
+        let ids = self
+            .pinned_processes
+            .iter_mut()
+            .map(|pinned| &mut pinned.id)
+            .chain(self.process_groups.iter_mut().map(|group| &mut group.id));
+        for id in ids {
+            if *id == 0 || !used_ids.insert(*id) {
+                *id = next_id;
+                used_ids.insert(next_id);
+                next_id += 1;
+            }
+        }
+        next_id != first_new_id
+    }
+
+    /// An id not used by any pinned process or process group. Ids start at 1,
+    /// as 0 marks an entry that has none yet.
+    pub fn next_monitor_id(&self) -> u32 {
+        self.pinned_processes
+            .iter()
+            .map(|pinned| pinned.id)
+            .chain(self.process_groups.iter().map(|group| group.id))
+            .max()
+            .unwrap_or(0)
+            + 1
+    }
+
+    /// Adds a pinned process monitor under a new id.
+    pub fn add_pinned_process(&mut self, mut pinned: PinnedProcessConfig) {
+        pinned.id = self.next_monitor_id();
+        self.pinned_processes.push(pinned);
+    }
+
+    /// Adds a process group monitor under a new id.
+    pub fn add_process_group(&mut self, mut group: ProcessGroupConfig) {
+        group.id = self.next_monitor_id();
+        self.process_groups.push(group);
+    }
+
+    pub fn pinned_process(&self, id: u32) -> Option<&PinnedProcessConfig> {
+        self.pinned_processes.iter().find(|pinned| pinned.id == id)
+    }
+
+    pub fn pinned_process_mut(&mut self, id: u32) -> Option<&mut PinnedProcessConfig> {
+        self.pinned_processes
+            .iter_mut()
+            .find(|pinned| pinned.id == id)
+    }
+
+    pub fn process_group(&self, id: u32) -> Option<&ProcessGroupConfig> {
+        self.process_groups.iter().find(|group| group.id == id)
+    }
+
+    pub fn process_group_mut(&mut self, id: u32) -> Option<&mut ProcessGroupConfig> {
+        self.process_groups.iter_mut().find(|group| group.id == id)
+    }
+
+    pub fn monitor_types(&self) -> Vec<MonitorTypeEnum> {
+        MonitorTypeEnum::builtin()
+            .chain(
+                self.pinned_processes
+                    .iter()
+                    .map(|pinned| MonitorTypeEnum::PinnedProcess(pinned.id)),
+            )
+            .chain(
+                self.process_groups
+                    .iter()
+                    .map(|group| MonitorTypeEnum::ProcessGroup(group.id)),
+            )
+            .collect()
+    }
+
+    pub fn is_monitor_active(&self, monitor_type: MonitorTypeEnum) -> bool {
+        match monitor_type {
+            MonitorTypeEnum::PinnedProcess(id) => {
+                self.pinned_process(id).is_some_and(|pinned| pinned.enabled)
+            }
+            MonitorTypeEnum::ProcessGroup(id) => {
+                self.process_group(id).is_some_and(|group| group.enabled)
+            }
+            _ => self.active_monitors.contains(&monitor_type),
+        }
+    }
+
+    pub fn set_monitor_active(&mut self, monitor_type: MonitorTypeEnum, active: bool) {
+        match monitor_type {
+            MonitorTypeEnum::PinnedProcess(id) => {
+                if let Some(pinned) = self.pinned_process_mut(id) {
+                    pinned.enabled = active;
+                }
+            }
+            MonitorTypeEnum::ProcessGroup(id) => {
+                if let Some(group) = self.process_group_mut(id) {
+                    group.enabled = active;
+                }
+            }
+            _ if active => {
+                self.active_monitors.insert(monitor_type);
+            }
+            _ => {
+                self.active_monitors.remove(&monitor_type);
+            }
+        }
+    }
+
+    /// Removes a process monitor entry. Built-in monitors can only be deactivated.
+    pub fn remove_monitor(&mut self, monitor_type: MonitorTypeEnum) {
+        match monitor_type {
+            MonitorTypeEnum::PinnedProcess(id) => {
+                self.pinned_processes.retain(|pinned| pinned.id != id);
+            }
+            MonitorTypeEnum::ProcessGroup(id) => {
+                self.process_groups.retain(|group| group.id != id);
+            }
+            _ => {}
+        }
+    }
+
+    /// Writes the config back to `path`. A file from a newer version is left
+    /// alone, as this build would drop the settings it doesn't know.
+    pub fn save(&self) -> Result<()> {
+        if self.config_version > config_migration::CURRENT_CONFIG_VERSION {
+            return Err(AppError::NewerConfig {
+                path: self.path.display().to_string(),
+                version: self.config_version,
+                supported: config_migration::CURRENT_CONFIG_VERSION,
+            });
+        }
         let toml_string = toml::to_string_pretty(self)?;
-        std::fs::write("config.toml", toml_string)?;
-        Ok(())
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| AppError::io(parent, e))?;
+        }
+        write_atomically(&self.path, &toml_string).map_err(|e| AppError::io(&self.path, e))
+    }
+}
+
+/// Writes `contents` to a temporary file next to `path` and renames it over
+/// `path`, so a crash mid-write never leaves a truncated config behind.
+fn write_atomically(path: &Path, contents: &str) -> std::io::Result<()> {
+    let mut temp_name = path.file_name().unwrap_or_default().to_os_string();
+    temp_name.push(".tmp");
+    let temp_path = path.with_file_name(temp_name);
+
+    let write_result = std::fs::File::create(&temp_path).and_then(|mut file| {
+        file.write_all(contents.as_bytes())?;
+        file.sync_all()
+    });
+
+    if let Err(e) = write_result.and_then(|()| std::fs::rename(&temp_path, path)) {
+        let _ = std::fs::remove_file(&temp_path);
+        return Err(e);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn group(id: u32, pattern: &str) -> ProcessGroupConfig {
+        ProcessGroupConfig {
+            id,
+            label: String::new(),
+            filter: ProcessFilterEnum::NamePattern(pattern.to_string()),
+            metric: ProcessMetricEnum::CpuUsage,
+            enabled: true,
+        }
+    }
+
+    fn pinned(id: u32, pid: u32) -> PinnedProcessConfig {
+        PinnedProcessConfig {
+            id,
+            label: String::new(),
+            rule: ProcessMatchRuleEnum::Pid(pid),
+            metric: ProcessMetricEnum::CpuUsage,
+            enabled: true,
+        }
+    }
+
+    #[test]
+    fn entries_without_or_with_duplicate_ids_get_new_ones() {
+        let mut app_config = AppConfig {
+            pinned_processes: vec![pinned(0, 10), pinned(4, 11)],
+            process_groups: vec![group(4, "code"), group(2, "firefox"), group(0, "make")],
+            ..AppConfig::default()
+        };
+
+        assert!(app_config.assign_monitor_ids());
+        assert!(!app_config.assign_monitor_ids());
+
+        let pinned_ids: Vec<u32> = app_config.pinned_processes.iter().map(|p| p.id).collect();
+        let group_ids: Vec<u32> = app_config.process_groups.iter().map(|g| g.id).collect();
+        assert_eq!(pinned_ids, [5, 4]);
+        assert_eq!(group_ids, [6, 2, 7]);
+    }
+
+    #[test]
+    fn added_entries_get_unused_ids() {
+        let mut app_config = AppConfig {
+            pinned_processes: vec![pinned(3, 10)],
+            ..AppConfig::default()
+        };
+
+        app_config.add_process_group(group(0, "code"));
+        app_config.add_pinned_process(pinned(0, 11));
+
+        assert_eq!(
+            app_config.monitor_types()[2..],
+            [
+                MonitorTypeEnum::PinnedProcess(3),
+                MonitorTypeEnum::PinnedProcess(5),
+                MonitorTypeEnum::ProcessGroup(4),
+            ]
+        );
+    }
+
+    #[test]
+    fn legacy_config_is_imported_only_when_none_exists() {
+        let dir = std::env::temp_dir().join(format!("rsm-import-{}", std::process::id()));
+        let legacy_file = dir.join("config.toml");
+        let path = dir.join("user").join("config.toml");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(&legacy_file, "language = \"Polish\"\n").unwrap();
+
+        let missing = dir.join("missing.toml");
+        let imported = AppConfig::import_from(&path, &[missing, legacy_file.clone()]);
+        assert_eq!(imported, Some(legacy_file.clone()));
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "language = \"Polish\"\n"
+        );
+
+        std::fs::write(&legacy_file, "language = \"English\"\n").unwrap();
+        assert_eq!(AppConfig::import_from(&path, &[legacy_file]), None);
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "language = \"Polish\"\n"
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn migrated_file_is_rewritten_after_a_backup() {
+        let dir = std::env::temp_dir().join(format!("rsm-migrate-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("config.toml");
+        let original = "[general]\nlanguage = \"English\"\n";
+        std::fs::write(&path, original).unwrap();
+
+        let app_config = AppConfig::new(&path).unwrap();
+
+        assert_eq!(
+            app_config.general.language,
+            LanguageSelectionEnum::Language("en-US".to_string())
+        );
+        assert_eq!(
+            std::fs::read_to_string(dir.join("config.toml.v0.bak")).unwrap(),
+            original
+        );
+        let migrated: toml::Table =
+            toml::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(migrated["general"]["language"].as_str(), Some("en-US"));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn config_is_not_migrated_without_a_backup() {
+        let dir = std::env::temp_dir().join(format!("rsm-no-backup-{}", std::process::id()));
+        // A directory in place of the backup file makes the copy fail.
+        std::fs::create_dir_all(dir.join("config.toml.v0.bak")).unwrap();
+        let path = dir.join("config.toml");
+        let original = "[general]\nlanguage = \"English\"\n";
+        std::fs::write(&path, original).unwrap();
+
+        assert!(matches!(AppConfig::new(&path), Err(AppError::Io { .. })));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), original);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn config_from_a_newer_version_is_not_overwritten() {
+        let dir = std::env::temp_dir().join(format!("rsm-newer-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("config.toml");
+        let contents = format!(
+            "config_version = {}\nfuture_setting = true\n",
+            config_migration::CURRENT_CONFIG_VERSION + 1
+        );
+        std::fs::write(&path, &contents).unwrap();
+
+        let app_config = AppConfig::new(&path).unwrap();
+
+        assert!(matches!(
+            app_config.save(),
+            Err(AppError::NewerConfig { .. })
+        ));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), contents);
+
+        std::fs::remove_dir_all(&dir).unwrap();
     }
 }
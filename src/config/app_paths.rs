@@ -0,0 +1,79 @@
+use std::path::PathBuf;
+
+const APP_DIR_NAME: &str = "rust_system_monitor";
+
+/// Per-user configuration directory, e.g. `$XDG_CONFIG_HOME/rust_system_monitor` on Linux.
+pub fn config_dir() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join(APP_DIR_NAME))
+}
+
+/// Default config file location. Falls back to the working directory on
+/// platforms without a config directory.
+pub fn config_file() -> PathBuf {
+    config_dir()
+        .map(|dir| dir.join("config.toml"))
+        .unwrap_or_else(|| PathBuf::from("config.toml"))
+}
+
+/// Where versions without a per-user config directory kept `config.toml`:
+/// the working directory, or next to the executable when started elsewhere.
+pub fn legacy_config_files() -> Vec<PathBuf> {
+    let mut files = vec![PathBuf::from("config.toml")];
+    if let Some(dir) = std::env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(PathBuf::from))
+    {
+        files.push(dir.join("config.toml"));
+    }
+    files
+}
+
+/// Directory whose `{lang}/main.ftl` files are layered over the bundled translations.
+pub fn translations_override_dir() -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join("translations"))
+}
+
+/// Directory holding the rotated log files, e.g. `$XDG_DATA_HOME/rust_system_monitor/logs` on Linux.
+pub fn log_dir() -> Option<PathBuf> {
+    dirs::data_local_dir().map(|dir| dir.join(APP_DIR_NAME).join("logs"))
+}
+
+/// Socket the running instance listens on.
+#[cfg(unix)]
+pub fn ipc_socket_path() -> PathBuf {
+    runtime_file("sock")
+}
+
+/// File the running instance keeps locked, next to its socket.
+#[cfg(unix)]
+pub fn instance_lock_path() -> PathBuf {
+    runtime_file("lock")
+}
+
+/// The shared temp directory fallback carries the user ID, so different users
+/// don't collide.
+#[cfg(unix)]
+fn runtime_file(extension: &str) -> PathBuf {
+    match dirs::runtime_dir() {
+        Some(dir) => dir.join(format!("{}.{}", APP_DIR_NAME, extension)),
+        None => {
+            // SAFETY: `getuid` has no preconditions and cannot fail.
+            let uid = unsafe { libc::getuid() };
+            std::env::temp_dir().join(format!("{}-{}.{}", APP_DIR_NAME, uid, extension))
+        }
+    }
+}
+
+/// Pipe the running instance listens on. Pipe names are shared by all users,
+/// so the name carries the user's SID.
+#[cfg(windows)]
+pub fn ipc_pipe_name(user_sid: &str) -> String {
+    format!(r"\\.\pipe\{}-{}", APP_DIR_NAME, user_sid)
+}
+
+/// Mutex that exists while an instance runs. It lives in the session's own
+/// namespace, where other users can't create it first.
+#[cfg(windows)]
+pub fn instance_mutex_name(user_sid: &str) -> String {
+    format!(r"Local\{}-{}", APP_DIR_NAME, user_sid)
+}
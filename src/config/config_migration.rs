@@ -0,0 +1,104 @@
+use std::path::{Path, PathBuf};
+use toml::{Table, Value};
+
+/// Version written by this build. Bump it together with a new entry in `MIGRATIONS`.
+pub const CURRENT_CONFIG_VERSION: u32 = 1;
+
+/// `MIGRATIONS[n]` upgrades a version `n` document to version `n + 1`.
+const MIGRATIONS: &[fn(&mut Table)] = &[migrate_v0_language_names];
+
+/// Upgrades `document` step by step to `CURRENT_CONFIG_VERSION`. Returns the
+/// version it started from, or `None` when it was already current.
+pub fn migrate(document: &mut Table) -> Option<u32> {
+    let version = document_version(document);
+    if version >= CURRENT_CONFIG_VERSION {
+        if version > CURRENT_CONFIG_VERSION {
+            tracing::warn!(
+                version,
+                supported = CURRENT_CONFIG_VERSION,
+                "Config version is newer than supported, changes will not be saved"
+            );
+        }
+        return None;
+    }
+
+    for migration in &MIGRATIONS[version as usize..] {
+        migration(document);
+    }
+    document.insert(
+        "config_version".to_string(),
+        Value::Integer(CURRENT_CONFIG_VERSION.into()),
+    );
+    Some(version)
+}
+
+/// Where the pre-migration copy of `path` is kept, e.g. `config.toml.v0.bak`.
+pub fn backup_path(path: &Path, version: u32) -> PathBuf {
+    let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(format!(".v{}.bak", version));
+    path.with_file_name(file_name)
+}
+
+/// Files written before versioning have no `config_version` and count as version 0.
+fn document_version(document: &Table) -> u32 {
+    document
+        .get("config_version")
+        .and_then(Value::as_integer)
+        .and_then(|version| u32::try_from(version).ok())
+        .unwrap_or(0)
+}
+
+/// Version 0 stored the language as an English name instead of an identifier.
+fn migrate_v0_language_names(document: &mut Table) {
+    let Some(Value::Table(general)) = document.get_mut("general") else {
+        return;
+    };
+    let Some(Value::String(language)) = general.get_mut("language") else {
+        return;
+    };
+
+    let language_id = match language.as_str() {
+        "Polish" => "pl",
+        "English" => "en-US",
+        _ => return,
+    };
+    *language = language_id.to_string();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn v0_language_names_become_identifiers() {
+        let mut document: Table =
+            toml::from_str("app_name = \"Monitor\"\n[general]\nlanguage = \"Polish\"\n").unwrap();
+
+        assert_eq!(migrate(&mut document), Some(0));
+        assert_eq!(document["general"]["language"].as_str(), Some("pl"));
+        assert_eq!(document["app_name"].as_str(), Some("Monitor"));
+        assert_eq!(
+            document["config_version"].as_integer(),
+            Some(CURRENT_CONFIG_VERSION.into())
+        );
+    }
+
+    #[test]
+    fn current_and_newer_documents_are_left_alone() {
+        for version in [CURRENT_CONFIG_VERSION, CURRENT_CONFIG_VERSION + 1] {
+            let source = format!("config_version = {version}\nunknown_key = 1\n");
+            let mut document: Table = toml::from_str(&source).unwrap();
+
+            assert_eq!(migrate(&mut document), None);
+            assert_eq!(document, toml::from_str::<Table>(&source).unwrap());
+        }
+    }
+
+    #[test]
+    fn backup_is_named_after_the_original_version() {
+        assert_eq!(
+            backup_path(Path::new("/etc/app/config.toml"), 0),
+            Path::new("/etc/app/config.toml.v0.bak")
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use crate::config::app_config::AppConfig;
+use std::time::{Duration, Instant};
+
+/// Keeps the settings in memory and the config file in step: decides when
+/// edits are saved and whether an external edit can be applied right away.
+pub struct ConfigSync {
+    /// The config as last read from or written to disk.
+    saved: AppConfig,
+    /// An external edit held back because it conflicts with unsaved settings.
+    pending_external: Option<AppConfig>,
+    /// Latest unsaved settings and when they last changed, for debounced saving.
+    last_edit: Option<(AppConfig, Instant)>,
+}
+
+impl ConfigSync {
+    pub fn new(saved: AppConfig) -> Self {
+        Self {
+            saved,
+            pending_external: None,
+            last_edit: None,
+        }
+    }
+
+    pub fn saved(&self) -> &AppConfig {
+        &self.saved
+    }
+
+    pub fn has_pending_external(&self) -> bool {
+        self.pending_external.is_some()
+    }
+
+    /// `false` while a held-back external edit waits for the user's choice.
+    pub fn may_save(&self) -> bool {
+        self.pending_external.is_none()
+    }
+
+    pub fn has_unsaved_changes(&self, current: &AppConfig) -> bool {
+        *current != self.saved
+    }
+
+    pub fn mark_saved(&mut self, saved: AppConfig) {
+        self.saved = saved;
+    }
+
+    /// Takes in the config just read from disk. Returns it when it should be
+    /// applied now; edits that clash with unsaved settings are held back.
+    pub fn external_change(&mut self, current: &AppConfig, loaded: AppConfig) -> Option<AppConfig> {
+        if loaded == self.saved {
+            return None;
+        }
+        if self.has_unsaved_changes(current) {
+            self.pending_external = Some(loaded);
+            None
+        } else {
+            Some(loaded)
+        }
+    }
+
+    /// Resolves a held-back edit: returns it when the user reloads, or drops it
+    /// when they keep their settings.
+    pub fn resolve_pending_external(&mut self, reload: bool) -> Option<AppConfig> {
+        self.pending_external.take().filter(|_| reload)
+    }
+
+    /// Whether `current` has stayed unchanged for `delay` and should be saved
+    /// now. Keeps returning `true` every `delay` while the save doesn't go
+    /// through, so failed saves are retried.
+    pub fn is_save_due(&mut self, current: &AppConfig, delay: Duration, now: Instant) -> bool {
+        if !self.has_unsaved_changes(current) || !self.may_save() {
+            self.last_edit = None;
+            return false;
+        }
+
+        match &self.last_edit {
+            Some((edited, at)) if edited == current => {
+                let due = now.saturating_duration_since(*at) >= delay;
+                if due {
+                    self.last_edit = Some((current.clone(), now));
+                }
+                due
+            }
+            _ => {
+                self.last_edit = Some((current.clone(), now));
+                false
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enums::language_selection_enum::LanguageSelectionEnum;
+
+    const DELAY: Duration = Duration::from_millis(500);
+
+    fn edited(config: &AppConfig) -> AppConfig {
+        let mut edited = config.clone();
+        edited.general.sampling_paused = !edited.general.sampling_paused;
+        edited
+    }
+
+    fn external(config: &AppConfig) -> AppConfig {
+        let mut external = config.clone();
+        external.general.language = LanguageSelectionEnum::Language("pl".to_string());
+        external
+    }
+
+    #[test]
+    fn external_edit_applies_without_unsaved_changes() {
+        let saved = AppConfig::default();
+        let mut sync = ConfigSync::new(saved.clone());
+
+        assert_eq!(sync.external_change(&saved, saved.clone()), None);
+        assert_eq!(
+            sync.external_change(&saved, external(&saved)),
+            Some(external(&saved))
+        );
+        assert!(sync.may_save());
+    }
+
+    #[test]
+    fn conflicting_external_edit_holds_back_saving() {
+        let saved = AppConfig::default();
+        let current = edited(&saved);
+        let mut sync = ConfigSync::new(saved.clone());
+
+        assert_eq!(sync.external_change(&current, external(&saved)), None);
+        assert!(sync.has_pending_external());
+        assert!(!sync.may_save());
+
+        let now = Instant::now();
+        assert!(!sync.is_save_due(&current, DELAY, now));
+        assert!(!sync.is_save_due(&current, DELAY, now + DELAY * 10));
+    }
+
+    #[test]
+    fn keeping_settings_drops_the_external_edit() {
+        let saved = AppConfig::default();
+        let current = edited(&saved);
+        let mut sync = ConfigSync::new(saved.clone());
+        sync.external_change(&current, external(&saved));
+
+        assert_eq!(sync.resolve_pending_external(false), None);
+        assert!(sync.may_save());
+    }
+
+    #[test]
+    fn reloading_returns_the_external_edit() {
+        let saved = AppConfig::default();
+        let current = edited(&saved);
+        let mut sync = ConfigSync::new(saved.clone());
+        sync.external_change(&current, external(&saved));
+
+        assert_eq!(sync.resolve_pending_external(true), Some(external(&saved)));
+        assert!(sync.may_save());
+    }
+
+    #[test]
+    fn save_is_due_once_edits_settle() {
+        let saved = AppConfig::default();
+        let mut sync = ConfigSync::new(saved.clone());
+        let start = Instant::now();
+
+        assert!(!sync.is_save_due(&saved, DELAY, start));
+
+        let current = edited(&saved);
+        assert!(!sync.is_save_due(&current, DELAY, start));
+        assert!(!sync.is_save_due(&current, DELAY, start + DELAY / 2));
+        assert!(sync.is_save_due(&current, DELAY, start + DELAY));
+        // Not saved yet, so it's due again after another delay.
+        assert!(!sync.is_save_due(&current, DELAY, start + DELAY * 3 / 2));
+        assert!(sync.is_save_due(&current, DELAY, start + DELAY * 2));
+
+        sync.mark_saved(current.clone());
+        assert!(!sync.is_save_due(&current, DELAY, start + DELAY * 4));
+    }
+}
@@ -0,0 +1,247 @@
+use crate::config::app_config::{AppConfig, RefreshConfig};
+use crate::enums::process_match_rule_enum::ProcessMatchRuleEnum;
+use crate::monitor::metric_history::MetricHistory;
+use std::fmt;
+use std::ops::RangeInclusive;
+
+/// A rule violation, located by the TOML path of the offending value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationIssue {
+    pub path: String,
+    pub message: String,
+}
+
+impl ValidationIssue {
+    fn new(path: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+/// Checks every rule and returns all violations, not just the first one.
+pub fn validate(app_config: &AppConfig) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+
+    if app_config.app_name.trim().is_empty() {
+        issues.push(ValidationIssue::new("app_name", "must not be empty"));
+    }
+
+    validate_refresh(&app_config.refresh, &mut issues);
+
+    if app_config.timing.ui_repaint_interval == 0 {
+        issues.push(ValidationIssue::new(
+            "timing.ui_repaint_interval",
+            "must be greater than 0",
+        ));
+    }
+
+    let max_minutes = MetricHistory::DEFAULT_RETENTION.as_secs() / 60;
+    let minutes = app_config.tooltip.statistics_window_minutes;
+    if !(1..=max_minutes).contains(&minutes) {
+        issues.push(ValidationIssue::new(
+            "tooltip.statistics_window_minutes",
+            format!("must be between 1 and {}, got {}", max_minutes, minutes),
+        ));
+    }
+
+    for (path, size) in [
+        ("window.settings_width", app_config.window.settings_width),
+        ("window.settings_height", app_config.window.settings_height),
+    ] {
+        if !(size.is_finite() && size > 0.0) {
+            issues.push(ValidationIssue::new(
+                path,
+                format!("must be a positive number, got {}", size),
+            ));
+        }
+    }
+
+    for (index, pinned) in app_config.pinned_processes.iter().enumerate() {
+        if let ProcessMatchRuleEnum::NamePattern(pattern) = &pinned.rule
+            && let Err(message) = check_pattern(pattern)
+        {
+            issues.push(ValidationIssue::new(
+                format!("pinned_processes[{}].rule", index),
+                message,
+            ));
+        }
+    }
+
+    for (index, group) in app_config.process_groups.iter().enumerate() {
+        if let Err(message) = check_pattern(group.filter.pattern()) {
+            issues.push(ValidationIssue::new(
+                format!("process_groups[{}].filter", index),
+                message,
+            ));
+        }
+    }
+
+    issues
+}
+
+fn validate_refresh(refresh: &RefreshConfig, issues: &mut Vec<ValidationIssue>) {
+    if refresh.min_refresh_seconds == 0 {
+        issues.push(ValidationIssue::new(
+            "refresh.min_refresh_seconds",
+            "must be at least 1",
+        ));
+    }
+
+    if refresh.min_refresh_seconds > refresh.max_refresh_seconds {
+        issues.push(ValidationIssue::new(
+            "refresh.max_refresh_seconds",
+            format!(
+                "must not be less than refresh.min_refresh_seconds ({}), got {}",
+                refresh.min_refresh_seconds, refresh.max_refresh_seconds
+            ),
+        ));
+    } else if !refresh_seconds_range(refresh).contains(&refresh.default_refresh_seconds) {
+        issues.push(ValidationIssue::new(
+            "refresh.default_refresh_seconds",
+            format!(
+                "must be between {} and {}, got {}",
+                refresh.min_refresh_seconds,
+                refresh.max_refresh_seconds,
+                refresh.default_refresh_seconds
+            ),
+        ));
+    }
+}
+
+/// Allowed values for `refresh.default_refresh_seconds`.
+pub fn refresh_seconds_range(refresh: &RefreshConfig) -> RangeInclusive<u64> {
+    refresh.min_refresh_seconds.max(1)..=refresh.max_refresh_seconds
+}
+
+/// Process patterns must be non-empty, valid regular expressions.
+pub fn check_pattern(pattern: &str) -> Result<(), String> {
+    if pattern.is_empty() {
+        return Err("pattern must not be empty".to_string());
+    }
+    regex::Regex::new(pattern)
+        .map(|_| ())
+        .map_err(|e| format!("invalid regular expression: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::app_config::{PinnedProcessConfig, ProcessGroupConfig};
+    use crate::enums::process_filter_enum::ProcessFilterEnum;
+    use crate::enums::process_metric_enum::ProcessMetricEnum;
+
+    fn paths(issues: &[ValidationIssue]) -> Vec<&str> {
+        issues.iter().map(|issue| issue.path.as_str()).collect()
+    }
+
+    #[test]
+    fn default_config_is_valid() {
+        assert_eq!(validate(&AppConfig::default()), []);
+    }
+
+    #[test]
+    fn every_violation_is_reported_with_its_path() {
+        let mut app_config = AppConfig::default();
+        app_config.refresh.min_refresh_seconds = 10;
+        app_config.refresh.max_refresh_seconds = 5;
+        app_config.window.settings_width = 0.0;
+        app_config.window.settings_height = f32::NAN;
+        app_config.pinned_processes.push(PinnedProcessConfig {
+            id: 1,
+            label: String::new(),
+            rule: ProcessMatchRuleEnum::NamePattern("fire(fox".to_string()),
+            metric: ProcessMetricEnum::CpuUsage,
+            enabled: true,
+        });
+        app_config.process_groups = ["code", "[unclosed"]
+            .into_iter()
+            .enumerate()
+            .map(|(index, pattern)| ProcessGroupConfig {
+                id: index as u32 + 2,
+                label: String::new(),
+                filter: ProcessFilterEnum::NamePattern(pattern.to_string()),
+                metric: ProcessMetricEnum::CpuUsage,
+                enabled: true,
+            })
+            .collect();
+
+        let issues = validate(&app_config);
+
+        assert_eq!(
+            paths(&issues),
+            [
+                "refresh.max_refresh_seconds",
+                "window.settings_width",
+                "window.settings_height",
+                "pinned_processes[0].rule",
+                "process_groups[1].filter",
+            ]
+        );
+        assert_eq!(
+            issues[0].message,
+            "must not be less than refresh.min_refresh_seconds (10), got 5"
+        );
+        assert_eq!(issues[1].message, "must be a positive number, got 0");
+        assert_eq!(issues[2].message, "must be a positive number, got NaN");
+        assert!(
+            issues[3]
+                .message
+                .starts_with("invalid regular expression: ")
+        );
+        assert!(
+            issues[4]
+                .message
+                .starts_with("invalid regular expression: ")
+        );
+    }
+
+    #[test]
+    fn refresh_bounds_are_checked() {
+        let mut refresh = RefreshConfig {
+            default_refresh_seconds: 4,
+            min_refresh_seconds: 0,
+            max_refresh_seconds: 60,
+        };
+        let mut issues = Vec::new();
+        validate_refresh(&refresh, &mut issues);
+        assert_eq!(
+            issues,
+            [ValidationIssue::new(
+                "refresh.min_refresh_seconds",
+                "must be at least 1"
+            )]
+        );
+
+        refresh.min_refresh_seconds = 5;
+        refresh.max_refresh_seconds = 30;
+        for default_refresh_seconds in [4, 31] {
+            refresh.default_refresh_seconds = default_refresh_seconds;
+            let mut issues = Vec::new();
+            validate_refresh(&refresh, &mut issues);
+            assert_eq!(
+                issues,
+                [ValidationIssue::new(
+                    "refresh.default_refresh_seconds",
+                    format!("must be between 5 and 30, got {}", default_refresh_seconds)
+                )]
+            );
+        }
+    }
+
+    #[test]
+    fn empty_patterns_are_rejected() {
+        assert_eq!(
+            check_pattern(""),
+            Err("pattern must not be empty".to_string())
+        );
+        assert_eq!(check_pattern("^fire(fox)?$"), Ok(()));
+    }
+}
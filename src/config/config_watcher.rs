@@ -0,0 +1,164 @@
+use crossbeam_channel::{Receiver, RecvTimeoutError, Sender};
+use std::path::{Path, PathBuf};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, SystemTime};
+
+/// Watches the config file for external edits by polling its modification time
+/// on a background thread.
+pub struct ConfigWatcher {
+    change_rx: Receiver<()>,
+    shutdown_tx: Option<Sender<()>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl ConfigWatcher {
+    const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+    /// Starts watching `path`. `on_change` runs on the watcher thread after each
+    /// detected change, e.g. to wake up the UI.
+    pub fn spawn(path: PathBuf, on_change: impl Fn() + Send + 'static) -> Self {
+        let (change_tx, change_rx) = crossbeam_channel::unbounded();
+        let (shutdown_tx, shutdown_rx) = crossbeam_channel::bounded(1);
+        // Taken before the thread starts, so changes made right after this
+        // returns aren't missed.
+        let mut tracker = ModificationTracker::new(path);
+
+        let handle = thread::Builder::new()
+            .name("config-watcher".to_string())
+            .spawn(move || {
+                while let Err(RecvTimeoutError::Timeout) =
+                    shutdown_rx.recv_timeout(Self::POLL_INTERVAL)
+                {
+                    if tracker.has_changed() {
+                        if change_tx.send(()).is_err() {
+                            break;
+                        }
+                        on_change();
+                    }
+                }
+            })
+            .inspect_err(|e| tracing::error!(error = %e, "Failed to spawn config watcher thread"))
+            .ok();
+
+        Self {
+            change_rx,
+            shutdown_tx: Some(shutdown_tx),
+            handle,
+        }
+    }
+
+    /// Returns whether the file changed since the last call.
+    pub fn has_changed(&self) -> bool {
+        self.change_rx.try_iter().count() > 0
+    }
+}
+
+impl Drop for ConfigWatcher {
+    fn drop(&mut self) {
+        if let Some(shutdown_tx) = self.shutdown_tx.take() {
+            let _ = shutdown_tx.send(());
+        }
+
+        if let Some(handle) = self.handle.take()
+            && let Err(e) = handle.join()
+        {
+            tracing::error!(panic = ?e, "Config watcher thread panicked during shutdown");
+        }
+    }
+}
+
+/// Remembers the file's modification time to tell when it changes. Creating
+/// or deleting the file counts as a change too.
+struct ModificationTracker {
+    path: PathBuf,
+    last_modified: Option<SystemTime>,
+}
+
+impl ModificationTracker {
+    fn new(path: PathBuf) -> Self {
+        let last_modified = modified_time(&path);
+        Self {
+            path,
+            last_modified,
+        }
+    }
+
+    fn has_changed(&mut self) -> bool {
+        let modified = modified_time(&self.path);
+        let changed = modified != self.last_modified;
+        self.last_modified = modified;
+        changed
+    }
+}
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    path.metadata()
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    fn set_modified(path: &Path, seconds: u64) {
+        let time = SystemTime::UNIX_EPOCH + Duration::from_secs(seconds);
+        File::options()
+            .write(true)
+            .open(path)
+            .unwrap()
+            .set_modified(time)
+            .unwrap();
+    }
+
+    #[test]
+    fn detects_modification_creation_and_removal() {
+        let dir = std::env::temp_dir().join(format!("rsm-watch-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("config.toml");
+        std::fs::write(&path, "").unwrap();
+        set_modified(&path, 1_000);
+
+        let mut tracker = ModificationTracker::new(path.clone());
+        assert!(!tracker.has_changed());
+
+        set_modified(&path, 2_000);
+        assert!(tracker.has_changed());
+        assert!(!tracker.has_changed());
+
+        std::fs::remove_file(&path).unwrap();
+        assert!(tracker.has_changed());
+        assert!(!tracker.has_changed());
+
+        std::fs::write(&path, "").unwrap();
+        assert!(tracker.has_changed());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn watcher_reports_each_change_once() {
+        let dir = std::env::temp_dir().join(format!("rsm-watcher-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("config.toml");
+        std::fs::write(&path, "").unwrap();
+        set_modified(&path, 1_000);
+
+        let (wake_tx, wake_rx) = crossbeam_channel::unbounded();
+        let watcher = ConfigWatcher::spawn(path.clone(), move || {
+            let _ = wake_tx.send(());
+        });
+        assert!(!watcher.has_changed());
+
+        set_modified(&path, 2_000);
+        wake_rx
+            .recv_timeout(ConfigWatcher::POLL_INTERVAL * 5)
+            .expect("change not detected");
+        assert!(watcher.has_changed());
+        assert!(!watcher.has_changed());
+
+        drop(watcher);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
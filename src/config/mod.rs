@@ -1 +1,6 @@
 pub mod app_config;
+pub mod app_paths;
+pub mod config_migration;
+pub mod config_sync;
+pub mod config_validation;
+pub mod config_watcher;
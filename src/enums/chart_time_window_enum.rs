@@ -0,0 +1,28 @@
+use std::time::Duration;
+use strum_macros::EnumIter;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, EnumIter)]
+pub enum ChartTimeWindowEnum {
+    #[default]
+    OneMinute,
+    TenMinutes,
+    OneHour,
+}
+
+impl ChartTimeWindowEnum {
+    pub fn duration(&self) -> Duration {
+        match self {
+            Self::OneMinute => Duration::from_secs(60),
+            Self::TenMinutes => Duration::from_secs(10 * 60),
+            Self::OneHour => Duration::from_secs(60 * 60),
+        }
+    }
+
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            Self::OneMinute => "dashboard-window-1m",
+            Self::TenMinutes => "dashboard-window-10m",
+            Self::OneHour => "dashboard-window-1h",
+        }
+    }
+}
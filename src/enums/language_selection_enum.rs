@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+const SYSTEM_DEFAULT: &str = "system";
+
+/// Language chosen in the settings. Stored in the config as `"system"` or as a
+/// language identifier such as `"pl"`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+#[serde(from = "String", into = "String")]
+pub enum LanguageSelectionEnum {
+    /// Follow the operating system's locale.
+    #[default]
+    SystemDefault,
+    Language(String),
+}
+
+impl From<String> for LanguageSelectionEnum {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            SYSTEM_DEFAULT => Self::SystemDefault,
+            _ => Self::Language(value),
+        }
+    }
+}
+
+impl From<LanguageSelectionEnum> for String {
+    fn from(selection: LanguageSelectionEnum) -> Self {
+        selection.to_string()
+    }
+}
+
+impl fmt::Display for LanguageSelectionEnum {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SystemDefault => write!(f, "{}", SYSTEM_DEFAULT),
+            Self::Language(id) => write!(f, "{}", id),
+        }
+    }
+}
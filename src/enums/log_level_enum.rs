@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default, EnumIter)]
+#[serde(rename_all = "lowercase")]
+pub enum LogLevelEnum {
+    Error,
+    Warn,
+    #[default]
+    Info,
+    Debug,
+    Trace,
+}
+
+impl LogLevelEnum {
+    /// Name used in filter directives, matching the serialized form.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LogLevelEnum::Error => "error",
+            LogLevelEnum::Warn => "warn",
+            LogLevelEnum::Info => "info",
+            LogLevelEnum::Debug => "debug",
+            LogLevelEnum::Trace => "trace",
+        }
+    }
+
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            LogLevelEnum::Error => "log-level-error",
+            LogLevelEnum::Warn => "log-level-warn",
+            LogLevelEnum::Info => "log-level-info",
+            LogLevelEnum::Debug => "log-level-debug",
+            LogLevelEnum::Trace => "log-level-trace",
+        }
+    }
+}
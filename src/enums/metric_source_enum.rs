@@ -0,0 +1,12 @@
+use std::path::PathBuf;
+
+/// Where `SystemMonitor` gets its data, chosen on the command line.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum MetricSourceEnum {
+    #[default]
+    Live,
+    /// Live data, also written to a recording.
+    Record(PathBuf),
+    /// A recording played back at `speed` times the recorded pace.
+    Replay { path: PathBuf, speed: f64 },
+}
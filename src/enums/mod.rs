@@ -1,4 +1,13 @@
+pub mod chart_time_window_enum;
+pub mod language_selection_enum;
+pub mod log_level_enum;
+pub mod metric_source_enum;
+pub mod monitor_details_enum;
 pub mod monitor_type_enum;
-pub mod supported_language_enum;
+pub mod monitor_unit_enum;
+pub mod process_action_enum;
+pub mod process_filter_enum;
+pub mod process_match_rule_enum;
+pub mod process_metric_enum;
 pub mod tray_menu_event_enum;
 pub mod ui_command_enum;
@@ -0,0 +1,15 @@
+/// Extra information a monitor reports next to its main value.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum MonitorDetailsEnum {
+    #[default]
+    None,
+    Memory {
+        used: u64,
+        total: u64,
+    },
+    ProcessGroup {
+        cpu_usage: f32,
+        memory: u64,
+        process_count: usize,
+    },
+}
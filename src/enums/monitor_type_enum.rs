@@ -1,17 +1,53 @@
+use crate::{config::app_config::AppConfig, i18n::i18n_manager::I18nManager};
 use serde::{Deserialize, Serialize};
-use strum_macros::EnumIter;
 
-#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum MonitorTypeEnum {
     CpuUsage,
     RamUsage,
+    /// `PinnedProcessConfig::id` of the entry.
+    PinnedProcess(u32),
+    /// `ProcessGroupConfig::id` of the entry.
+    ProcessGroup(u32),
 }
 
 impl MonitorTypeEnum {
+    pub fn builtin() -> impl Iterator<Item = Self> {
+        [Self::CpuUsage, Self::RamUsage].iter().copied()
+    }
+
+    /// Stable name used by the IPC commands, e.g. "cpu" or "group-3".
+    pub fn id(&self) -> String {
+        match self {
+            MonitorTypeEnum::CpuUsage => "cpu".to_string(),
+            MonitorTypeEnum::RamUsage => "ram".to_string(),
+            MonitorTypeEnum::PinnedProcess(id) => format!("pinned-{}", id),
+            MonitorTypeEnum::ProcessGroup(id) => format!("group-{}", id),
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Self> {
+        match id {
+            "cpu" => Some(MonitorTypeEnum::CpuUsage),
+            "ram" => Some(MonitorTypeEnum::RamUsage),
+            _ => {
+                if let Some(id) = id.strip_prefix("pinned-") {
+                    id.parse().ok().map(MonitorTypeEnum::PinnedProcess)
+                } else if let Some(id) = id.strip_prefix("group-") {
+                    id.parse().ok().map(MonitorTypeEnum::ProcessGroup)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
     pub fn icon_label_key(&self) -> &'static str {
         match self {
             MonitorTypeEnum::CpuUsage => "icon-label-cpu-usage",
             MonitorTypeEnum::RamUsage => "icon-label-ram-usage",
+            MonitorTypeEnum::PinnedProcess(_) => "icon-label-pinned-process",
+            MonitorTypeEnum::ProcessGroup(_) => "icon-label-process-group",
         }
     }
 
@@ -19,12 +55,62 @@ impl MonitorTypeEnum {
         match self {
             MonitorTypeEnum::CpuUsage => "tray-tooltip-cpu-usage",
             MonitorTypeEnum::RamUsage => "tray-tooltip-ram-usage",
+            MonitorTypeEnum::PinnedProcess(_) => "tray-tooltip-pinned-process",
+            MonitorTypeEnum::ProcessGroup(_) => "tray-tooltip-process-group",
         }
     }
 
-    pub fn unit(&self) -> &'static str {
-        match self {
-            MonitorTypeEnum::CpuUsage | MonitorTypeEnum::RamUsage => "%",
+    /// Process monitors use their configured label and fall back to the translated one.
+    pub fn icon_label(&self, i18n: &I18nManager, app_config: &AppConfig) -> String {
+        let configured_label = match self {
+            MonitorTypeEnum::PinnedProcess(id) => app_config
+                .pinned_process(*id)
+                .map(|pinned| pinned.label.as_str()),
+            MonitorTypeEnum::ProcessGroup(id) => app_config
+                .process_group(*id)
+                .map(|group| group.label.as_str()),
+            _ => None,
+        };
+
+        match configured_label {
+            Some(label) if !label.is_empty() => label.to_string(),
+            _ => i18n.get_message(self.icon_label_key()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids_round_trip() {
+        for monitor_type in [
+            MonitorTypeEnum::CpuUsage,
+            MonitorTypeEnum::RamUsage,
+            MonitorTypeEnum::PinnedProcess(2),
+            MonitorTypeEnum::ProcessGroup(3),
+        ] {
+            assert_eq!(
+                MonitorTypeEnum::from_id(&monitor_type.id()),
+                Some(monitor_type)
+            );
+        }
+        assert_eq!(MonitorTypeEnum::ProcessGroup(3).id(), "group-3");
+    }
+
+    #[test]
+    fn unknown_ids_are_rejected() {
+        for id in [
+            "",
+            "gpu",
+            "CPU",
+            "group-",
+            "group-x",
+            "pinned--1",
+            "group-3 ",
+        ] {
+            assert_eq!(MonitorTypeEnum::from_id(id), None, "{:?}", id);
         }
     }
 }
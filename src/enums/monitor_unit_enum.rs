@@ -0,0 +1,26 @@
+use crate::i18n::i18n_manager::I18nManager;
+use serde::Serialize;
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum MonitorUnitEnum {
+    Percent,
+    Bytes,
+}
+
+impl MonitorUnitEnum {
+    pub fn format_value(&self, value: f32, i18n: &I18nManager) -> String {
+        match self {
+            MonitorUnitEnum::Percent => i18n.format_percent(value as f64),
+            MonitorUnitEnum::Bytes => i18n.format_bytes(value as f64),
+        }
+    }
+
+    /// Shorter variant of `format_value` for the tray icon.
+    pub fn format_icon_value(&self, value: f32, i18n: &I18nManager) -> String {
+        match self {
+            MonitorUnitEnum::Percent => i18n.format_percent(value as f64),
+            MonitorUnitEnum::Bytes => i18n.format_bytes_compact(value as f64),
+        }
+    }
+}
@@ -0,0 +1,27 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcessActionEnum {
+    Terminate,
+    Kill,
+    Renice(i32),
+}
+
+impl ProcessActionEnum {
+    pub const MIN_NICE: i32 = -20;
+    pub const MAX_NICE: i32 = 19;
+
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            ProcessActionEnum::Terminate => "process-action-terminate",
+            ProcessActionEnum::Kill => "process-action-kill",
+            ProcessActionEnum::Renice(_) => "process-action-renice",
+        }
+    }
+
+    pub fn confirm_key(&self) -> &'static str {
+        match self {
+            ProcessActionEnum::Terminate => "process-confirm-terminate",
+            ProcessActionEnum::Kill => "process-confirm-kill",
+            ProcessActionEnum::Renice(_) => "process-confirm-renice",
+        }
+    }
+}
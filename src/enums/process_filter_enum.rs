@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum ProcessFilterEnum {
+    /// Regular expression matched against the process name.
+    NamePattern(String),
+    /// Regular expression matched against the full command line.
+    CommandLinePattern(String),
+}
+
+impl ProcessFilterEnum {
+    pub fn pattern(&self) -> &str {
+        match self {
+            Self::NamePattern(pattern) | Self::CommandLinePattern(pattern) => pattern,
+        }
+    }
+}
+
+impl fmt::Display for ProcessFilterEnum {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NamePattern(pattern) => write!(f, "{}", pattern),
+            Self::CommandLinePattern(pattern) => write!(f, "cmd: {}", pattern),
+        }
+    }
+}
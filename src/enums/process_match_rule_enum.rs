@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum ProcessMatchRuleEnum {
+    Pid(u32),
+    /// Regular expression matched against the process name.
+    NamePattern(String),
+}
+
+impl fmt::Display for ProcessMatchRuleEnum {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pid(pid) => write!(f, "PID {}", pid),
+            Self::NamePattern(pattern) => write!(f, "{}", pattern),
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use crate::enums::monitor_unit_enum::MonitorUnitEnum;
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default, EnumIter)]
+pub enum ProcessMetricEnum {
+    #[default]
+    CpuUsage,
+    Memory,
+}
+
+impl ProcessMetricEnum {
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            ProcessMetricEnum::CpuUsage => "process-metric-cpu-usage",
+            ProcessMetricEnum::Memory => "process-metric-memory",
+        }
+    }
+
+    pub fn unit(&self) -> MonitorUnitEnum {
+        match self {
+            ProcessMetricEnum::CpuUsage => MonitorUnitEnum::Percent,
+            ProcessMetricEnum::Memory => MonitorUnitEnum::Bytes,
+        }
+    }
+}
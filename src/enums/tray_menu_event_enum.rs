@@ -1,5 +1,10 @@
+use crate::enums::monitor_type_enum::MonitorTypeEnum;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum TrayMenuEventEnum {
     Settings,
+    Dashboard,
+    ToggleMonitor(MonitorTypeEnum),
+    TogglePause,
     Quit,
 }
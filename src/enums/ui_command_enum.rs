@@ -1,5 +1,13 @@
+use crate::enums::monitor_type_enum::MonitorTypeEnum;
+use std::sync::mpsc;
+
 #[derive(Debug)]
 pub enum UICommandEnum {
     ShowSettings,
+    ShowDashboard,
+    SetMonitorActive(MonitorTypeEnum, bool),
+    SetPaused(bool),
+    /// Replies with a JSON snapshot of the latest samples.
+    GetStats(mpsc::Sender<String>),
     Quit,
 }
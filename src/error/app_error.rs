@@ -1,9 +1,55 @@
+use crate::config::config_validation::ValidationIssue;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
 pub enum AppError {
     #[error("Config error: {0}")]
-    Config(#[from] confy::ConfyError),
+    Config(#[from] config::ConfigError),
+
+    #[error("Config file is not valid TOML: {0}")]
+    ConfigParse(#[from] toml::de::Error),
+
+    #[error("Failed to serialize config: {0}")]
+    ConfigSerialize(#[from] toml::ser::Error),
+
+    #[error(
+        "{path} is from a newer version (config version {version} > {supported}), not overwriting"
+    )]
+    NewerConfig {
+        path: String,
+        version: u32,
+        supported: u32,
+    },
+
+    #[error("Invalid settings:\n{}", join_issues(.0))]
+    InvalidConfig(Vec<ValidationIssue>),
+
+    #[error("Failed to access {path}: {source}")]
+    Io {
+        path: String,
+        source: std::io::Error,
+    },
+
+    #[error("Failed to locate the application executable: {0}")]
+    CurrentExe(std::io::Error),
+
+    #[error("Failed to start the {name} thread: {source}")]
+    ThreadSpawn {
+        name: String,
+        source: std::io::Error,
+    },
+
+    #[error("Command endpoint error: {0}")]
+    Ipc(std::io::Error),
+
+    #[error("Autostart error: {0}")]
+    Autostart(#[from] auto_launch::Error),
+
+    #[error("Failed to load the icon font: {0}")]
+    Font(#[from] ab_glyph::InvalidFont),
+
+    #[error("Window error: {0}")]
+    Gui(#[from] eframe::Error),
 
     #[error("Tray icon error: {0}")]
     Tray(#[from] tray_icon::Error),
@@ -13,6 +59,54 @@ pub enum AppError {
 
     #[error("Icon creation error: {0}")]
     BadIcon(#[from] tray_icon::BadIcon),
+
+    #[error("Failed to read translation file {path}: {source}")]
+    TranslationRead {
+        path: String,
+        source: std::io::Error,
+    },
+
+    #[error("Translation file {path} contains errors: {details}")]
+    TranslationParse { path: String, details: String },
+
+    #[error("'{0}' is not a valid language identifier")]
+    InvalidLanguage(String),
+
+    #[error("No translations could be loaded for '{0}'")]
+    NoTranslations(String),
+
+    #[error("Process {pid} action failed: {source}")]
+    ProcessAction { pid: u32, source: std::io::Error },
+
+    #[error("Invalid command line: {0}")]
+    InvalidArgument(String),
+
+    #[error("Recording {path} is invalid at line {line}: {source}")]
+    RecordingParse {
+        path: String,
+        line: usize,
+        source: serde_json::Error,
+    },
+
+    #[error("Recording {0} contains no samples")]
+    EmptyRecording(String),
+}
+
+impl AppError {
+    pub fn io(path: &std::path::Path, source: std::io::Error) -> Self {
+        Self::Io {
+            path: path.display().to_string(),
+            source,
+        }
+    }
+}
+
+fn join_issues(issues: &[ValidationIssue]) -> String {
+    issues
+        .iter()
+        .map(ValidationIssue::to_string)
+        .collect::<Vec<_>>()
+        .join("\n")
 }
 
 pub type Result<T> = std::result::Result<T, AppError>;
@@ -0,0 +1,13 @@
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+/// Locking that survives a poisoned mutex. The shared state here is plain
+/// settings and translations, which stay usable after a panic elsewhere.
+pub trait LockExt<T> {
+    fn lock_or_recover(&self) -> MutexGuard<'_, T>;
+}
+
+impl<T> LockExt<T> for Mutex<T> {
+    fn lock_or_recover(&self) -> MutexGuard<'_, T> {
+        self.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
@@ -0,0 +1,16 @@
+/// Translations compiled into the binary, keyed by language identifier.
+const EMBEDDED_TRANSLATIONS: &[(&str, &str)] = &[
+    ("en-US", include_str!("../../translations/en-US/main.ftl")),
+    ("pl", include_str!("../../translations/pl/main.ftl")),
+];
+
+pub fn get(lang_id: &str) -> Option<&'static str> {
+    EMBEDDED_TRANSLATIONS
+        .iter()
+        .find(|(id, _)| *id == lang_id)
+        .map(|(_, ftl)| *ftl)
+}
+
+pub fn language_ids() -> impl Iterator<Item = &'static str> {
+    EMBEDDED_TRANSLATIONS.iter().map(|(id, _)| *id)
+}
@@ -1,50 +1,468 @@
-use fluent::FluentResource;
 use fluent::concurrent::FluentBundle;
+use fluent::{FluentArgs, FluentResource, fluent_args};
+use fluent_langneg::{NegotiationStrategy, negotiate_languages};
+use locale_config::Locale;
+use std::collections::{BTreeSet, HashSet};
 use std::fs;
-use std::path::Path;
+use std::io;
+use std::sync::Mutex;
+use unic_langid::LanguageIdentifier;
 
-use crate::enums::supported_language_enum::SupportedLanguageEnum;
+use crate::config::app_paths;
+use crate::enums::language_selection_enum::LanguageSelectionEnum;
+use crate::error::app_error::{AppError, Result};
+use crate::error::lock_ext::LockExt;
+use crate::i18n::{embedded_translations, number_formatter::NumberFormatter};
+
+const KIB: f64 = 1024.0;
+const MIB: f64 = KIB * 1024.0;
+const GIB: f64 = MIB * 1024.0;
+
+/// Language consulted when a message is missing from the selected one.
+pub const FALLBACK_LANGUAGE: &str = "en-US";
+
+/// A language with translations available, embedded or in the override directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LanguageInfo {
+    pub id: String,
+    /// Native name read from the language's own `language-name` message.
+    pub name: String,
+}
+
+/// The options of the language setting. Building them parses every
+/// translation file, so they are loaded once and refreshed on config reloads
+/// rather than on every frame.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LanguageChoices {
+    pub languages: Vec<LanguageInfo>,
+    /// Name of the language the system default currently resolves to.
+    pub system_language_name: String,
+}
+
+impl LanguageChoices {
+    pub fn load() -> Self {
+        let languages = I18nManager::available_languages();
+        let system_id = I18nManager::resolve_language(&LanguageSelectionEnum::SystemDefault);
+        let system_language_name = languages
+            .iter()
+            .find(|lang| lang.id == system_id)
+            .map_or(system_id.clone(), |lang| lang.name.clone());
+        Self {
+            languages,
+            system_language_name,
+        }
+    }
+}
 
 pub struct I18nManager {
-    bundle: FluentBundle<FluentResource>,
+    /// Locale chain, most preferred first. Lookups walk it until a message is found.
+    bundles: Vec<FluentBundle<FluentResource>>,
+    number_formatter: NumberFormatter,
+    /// Message IDs already warned about, so a broken message isn't logged every frame.
+    reported_missing: Mutex<HashSet<String>>,
 }
 
 impl I18nManager {
-    pub fn new(lang: SupportedLanguageEnum) -> Self {
-        let lang_id = lang.id();
+    pub fn new(selection: &LanguageSelectionEnum) -> Result<Self> {
+        let lang = Self::resolve_language(selection);
+        let lang = lang.as_str();
+        let mut chain = vec![lang];
+        if lang != FALLBACK_LANGUAGE {
+            chain.push(FALLBACK_LANGUAGE);
+        }
+
+        let mut bundles = Vec::with_capacity(chain.len());
+        for chain_lang in chain {
+            match Self::load_bundle(chain_lang) {
+                Ok(bundle) => bundles.push(bundle),
+                Err(e) => tracing::warn!(error = %e, "Skipping language in fallback chain"),
+            }
+        }
+
+        if bundles.is_empty() {
+            return Err(AppError::NoTranslations(lang.to_string()));
+        }
+
+        Ok(Self::from_bundles(lang, bundles))
+    }
+
+    /// `bundles` is the non-empty locale chain, `lang` first.
+    fn from_bundles(lang: &str, bundles: Vec<FluentBundle<FluentResource>>) -> Self {
+        Self {
+            bundles,
+            number_formatter: NumberFormatter::for_language(
+                &lang.parse::<LanguageIdentifier>().unwrap_or_default(),
+            ),
+            reported_missing: Mutex::new(HashSet::new()),
+        }
+    }
+
+    /// Loads a single language from the embedded translations, with the user's
+    /// override file layered on top when present.
+    fn load_bundle(lang_id: &str) -> Result<FluentBundle<FluentResource>> {
+        let mut bundle = Self::empty_bundle(lang_id)?;
+        let mut loaded = false;
+
+        if let Some(ftl) = embedded_translations::get(lang_id) {
+            let origin = format!("embedded {}/main.ftl", lang_id);
+            Self::add_source(&mut bundle, &origin, ftl.to_string(), false);
+            loaded = true;
+        }
+
+        if let Some(path) =
+            app_paths::translations_override_dir().map(|dir| dir.join(lang_id).join("main.ftl"))
+        {
+            match fs::read_to_string(&path) {
+                Ok(ftl) => {
+                    Self::add_source(&mut bundle, &path.display().to_string(), ftl, true);
+                    loaded = true;
+                }
+                Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+                Err(source) => tracing::warn!(
+                    error = %AppError::TranslationRead {
+                        path: path.display().to_string(),
+                        source,
+                    },
+                    "Ignoring translation override"
+                ),
+            }
+        }
+
+        if !loaded {
+            return Err(AppError::NoTranslations(lang_id.to_string()));
+        }
+
+        Ok(bundle)
+    }
+
+    fn empty_bundle(lang_id: &str) -> Result<FluentBundle<FluentResource>> {
+        let locale: LanguageIdentifier = lang_id
+            .parse()
+            .map_err(|_| AppError::InvalidLanguage(lang_id.to_string()))?;
+        let mut bundle = FluentBundle::new_concurrent(vec![locale]);
+        // Isolation marks around placeables show up as stray glyphs in tray tooltips.
+        bundle.set_use_isolating(false);
+        Ok(bundle)
+    }
+
+    /// Maps the configured language to one with translations. `SystemDefault`
+    /// negotiates the OS locales against the available languages, so e.g. a
+    /// "pl-PL" system picks "pl".
+    pub fn resolve_language(selection: &LanguageSelectionEnum) -> String {
+        match selection {
+            LanguageSelectionEnum::Language(id) => id.clone(),
+            LanguageSelectionEnum::SystemDefault => Self::system_language(),
+        }
+    }
 
-        let ftl_path_str = format!("translations/{}/main.ftl", lang_id);
-        let ftl_path = Path::new(&ftl_path_str);
+    fn system_language() -> String {
+        let requested: Vec<LanguageIdentifier> = Locale::user_default()
+            .tags_for("messages")
+            .filter_map(|range| range.to_string().parse().ok())
+            .collect();
+        let available: Vec<LanguageIdentifier> = Self::language_ids()
+            .iter()
+            .filter_map(|id| id.parse().ok())
+            .collect();
+        let default: LanguageIdentifier = FALLBACK_LANGUAGE.parse().unwrap_or_default();
 
-        let ftl_string = fs::read_to_string(ftl_path).unwrap_or_else(|_| {
-            println!(
-                "Warning: Language file for '{}' not found. Falling back to 'en-US'.",
-                lang
+        negotiate_languages(
+            &requested,
+            &available,
+            Some(&default),
+            NegotiationStrategy::Lookup,
+        )
+        .first()
+        .map(|id| id.to_string())
+        .unwrap_or_else(|| FALLBACK_LANGUAGE.to_string())
+    }
+
+    fn language_ids() -> BTreeSet<String> {
+        let mut ids: BTreeSet<String> = embedded_translations::language_ids()
+            .map(str::to_string)
+            .collect();
+
+        if let Some(entries) =
+            app_paths::translations_override_dir().and_then(|dir| fs::read_dir(dir).ok())
+        {
+            ids.extend(
+                entries
+                    .flatten()
+                    .filter(|entry| entry.path().join("main.ftl").is_file())
+                    .filter_map(|entry| entry.file_name().into_string().ok()),
             );
-            fs::read_to_string("translations/en-US/main.ftl")
-                .expect("Failed to read fallback FTL file (en-US)")
+        }
+
+        ids
+    }
+
+    /// Languages found in the embedded translations and the override directory,
+    /// sorted by their native name.
+    pub fn available_languages() -> Vec<LanguageInfo> {
+        let mut languages: Vec<LanguageInfo> = Self::language_ids()
+            .into_iter()
+            .filter_map(|id| {
+                let bundle = Self::load_bundle(&id).ok()?;
+                let name = bundle
+                    .get_message("language-name")
+                    .and_then(|msg| msg.value())
+                    .map(|pattern| {
+                        bundle
+                            .format_pattern(pattern, None, &mut vec![])
+                            .to_string()
+                    })
+                    .unwrap_or_else(|| id.clone());
+                Some(LanguageInfo { id, name })
+            })
+            .collect();
+        languages.sort_by(|a, b| a.name.cmp(&b.name));
+        languages
+    }
+
+    /// Syntax errors only drop the broken entries: the rest of the file is still
+    /// used and the errors are logged.
+    fn add_source(
+        bundle: &mut FluentBundle<FluentResource>,
+        origin: &str,
+        ftl: String,
+        overriding: bool,
+    ) {
+        let resource = FluentResource::try_new(ftl).unwrap_or_else(|(resource, errors)| {
+            let error = AppError::TranslationParse {
+                path: origin.to_string(),
+                details: format!("{:?}", errors),
+            };
+            tracing::warn!(%error, "Loaded translations partially");
+            resource
         });
 
-        let resource = FluentResource::try_new(ftl_string).expect("Failed to parse FTL string");
+        if overriding {
+            bundle.add_resource_overriding(resource);
+        } else if let Err(errors) = bundle.add_resource(resource) {
+            tracing::warn!(origin, ?errors, "Duplicate messages were ignored");
+        }
+    }
+
+    pub fn get_message(&self, id: &str) -> String {
+        self.format_message(id, None)
+    }
 
-        let mut bundle = FluentBundle::new_concurrent(vec![lang_id]);
+    pub fn get_message_with_args(&self, id: &str, args: &FluentArgs) -> String {
+        self.format_message(id, Some(args))
+    }
+
+    pub fn format_decimal(&self, value: f64, fraction_digits: usize) -> String {
+        self.number_formatter.format_decimal(value, fraction_digits)
+    }
+
+    pub fn format_percent(&self, value: f64) -> String {
+        self.get_message_with_args(
+            "unit-percent",
+            &fluent_args!["value" => self.format_decimal(value, 0)],
+        )
+    }
+
+    /// Formats a byte count with a binary-scaled unit, e.g. "1.5 GiB" or "1,5 GiB".
+    pub fn format_bytes(&self, bytes: f64) -> String {
+        let (id, value) = self.scale_bytes(bytes, "unit");
+        self.get_message_with_args(&id, &fluent_args!["value" => value])
+    }
+
+    /// Short byte format that fits on a 32px tray icon, e.g. "1.5G".
+    pub fn format_bytes_compact(&self, bytes: f64) -> String {
+        let (id, value) = self.scale_bytes(bytes, "icon-value");
+        self.get_message_with_args(&id, &fluent_args!["value" => value])
+    }
+
+    fn scale_bytes(&self, bytes: f64, prefix: &str) -> (String, String) {
+        let (suffix, value) = if bytes >= GIB {
+            ("gigabytes", self.format_decimal(bytes / GIB, 1))
+        } else if bytes >= MIB {
+            ("megabytes", self.format_decimal(bytes / MIB, 0))
+        } else {
+            ("kilobytes", self.format_decimal(bytes / KIB, 0))
+        };
+        (format!("{}-{}", prefix, suffix), value)
+    }
+
+    fn format_message(&self, id: &str, args: Option<&FluentArgs>) -> String {
+        let primary_locale = &self.bundles[0].locales[0];
+
+        for (position, bundle) in self.bundles.iter().enumerate() {
+            let Some(pattern) = bundle.get_message(id).and_then(|msg| msg.value()) else {
+                continue;
+            };
+
+            if position > 0 {
+                self.warn_once(
+                    id,
+                    format!(
+                        "Message '{}' is missing for '{}', using '{}'.",
+                        id, primary_locale, bundle.locales[0]
+                    ),
+                );
+            }
+
+            let mut errors = vec![];
+            let value = bundle
+                .format_pattern(pattern, args, &mut errors)
+                .to_string();
+            if !errors.is_empty() {
+                self.warn_once(
+                    id,
+                    format!(
+                        "Message '{}' could not be fully formatted: {:?}",
+                        id, errors
+                    ),
+                );
+            }
+            return value;
+        }
+
+        self.warn_once(
+            id,
+            format!(
+                "Message '{}' is missing for '{}' and its fallbacks, showing the ID.",
+                id, primary_locale
+            ),
+        );
+        id.to_string()
+    }
+
+    fn warn_once(&self, id: &str, warning: String) {
+        let mut reported = self.reported_missing.lock_or_recover();
+        if reported.insert(id.to_string()) {
+            tracing::warn!("{}", warning);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds the manager from the embedded translations only, so overrides in
+    /// the user's config directory don't affect the tests.
+    fn embedded_manager(lang: &str) -> I18nManager {
+        let mut chain = vec![lang];
+        if lang != FALLBACK_LANGUAGE {
+            chain.push(FALLBACK_LANGUAGE);
+        }
+        let bundles = chain
+            .into_iter()
+            .map(|lang_id| {
+                let mut bundle = I18nManager::empty_bundle(lang_id).unwrap();
+                let ftl = embedded_translations::get(lang_id).unwrap();
+                I18nManager::add_source(&mut bundle, lang_id, ftl.to_string(), false);
+                bundle
+            })
+            .collect();
+        I18nManager::from_bundles(lang, bundles)
+    }
+
+    fn bundle(lang_id: &str, ftl: &str) -> FluentBundle<FluentResource> {
+        let mut bundle = I18nManager::empty_bundle(lang_id).unwrap();
+        I18nManager::add_source(&mut bundle, lang_id, ftl.to_string(), false);
         bundle
-            .add_resource(resource)
-            .expect("Failed to add FTL resource to bundle");
+    }
+
+    /// Counts warnings logged while `f` runs.
+    fn count_warnings(f: impl FnOnce()) -> usize {
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::{Event, Level, Metadata};
+
+        struct WarningCounter(Arc<AtomicUsize>);
+
+        impl tracing::Subscriber for WarningCounter {
+            fn enabled(&self, _: &Metadata<'_>) -> bool {
+                true
+            }
+            fn new_span(&self, _: &Attributes<'_>) -> Id {
+                Id::from_u64(1)
+            }
+            fn record(&self, _: &Id, _: &Record<'_>) {}
+            fn record_follows_from(&self, _: &Id, _: &Id) {}
+            fn event(&self, event: &Event<'_>) {
+                if *event.metadata().level() == Level::WARN {
+                    self.0.fetch_add(1, Ordering::SeqCst);
+                }
+            }
+            fn enter(&self, _: &Id) {}
+            fn exit(&self, _: &Id) {}
+        }
 
-        Self { bundle }
+        let count = Arc::new(AtomicUsize::new(0));
+        tracing::subscriber::with_default(WarningCounter(count.clone()), f);
+        count.load(Ordering::SeqCst)
     }
 
-    pub fn get_message(&self, id: &str) -> String {
-        let msg = self
-            .bundle
-            .get_message(id)
-            .expect(&format!("Message '{}' not found", id));
-        let mut errors = vec![];
-        let pattern = msg.value().expect("Message has no value");
-
-        self.bundle
-            .format_pattern(pattern, None, &mut errors)
-            .to_string()
+    fn partial_polish() -> I18nManager {
+        I18nManager::from_bundles(
+            "pl",
+            vec![
+                bundle("pl", "greeting = Cześć"),
+                bundle("en-US", "greeting = Hello\nfarewell = Goodbye"),
+            ],
+        )
+    }
+
+    #[test]
+    fn prefers_the_selected_locale() {
+        let i18n = partial_polish();
+        let warnings = count_warnings(|| assert_eq!(i18n.get_message("greeting"), "Cześć"));
+        assert_eq!(warnings, 0);
+    }
+
+    #[test]
+    fn falls_back_to_english_and_warns_once() {
+        let i18n = partial_polish();
+        let warnings = count_warnings(|| {
+            assert_eq!(i18n.get_message("farewell"), "Goodbye");
+            assert_eq!(i18n.get_message("farewell"), "Goodbye");
+        });
+        assert_eq!(warnings, 1);
+    }
+
+    #[test]
+    fn shows_the_id_of_a_message_missing_everywhere_and_warns_once() {
+        let i18n = partial_polish();
+        let warnings = count_warnings(|| {
+            assert_eq!(i18n.get_message("unknown-message"), "unknown-message");
+            assert_eq!(i18n.get_message("unknown-message"), "unknown-message");
+        });
+        assert_eq!(warnings, 1);
+    }
+
+    #[test]
+    fn formats_bytes_with_binary_units() {
+        let en = embedded_manager("en-US");
+        assert_eq!(en.format_bytes(1.5 * GIB), "1.5 GiB");
+        assert_eq!(en.format_bytes(300.0 * MIB), "300 MiB");
+        assert_eq!(en.format_bytes(2.0 * KIB), "2 KiB");
+        assert_eq!(en.format_bytes_compact(1.5 * GIB), "1.5G");
+
+        let pl = embedded_manager("pl");
+        assert_eq!(pl.format_bytes(1.5 * GIB), "1,5 GiB");
+        assert_eq!(pl.format_bytes(300.0 * MIB), "300 MiB");
+        assert_eq!(pl.format_bytes_compact(1.5 * GIB), "1,5G");
+    }
+
+    #[test]
+    fn formats_memory_in_the_same_unit_everywhere() {
+        for lang in ["en-US", "pl"] {
+            let i18n = embedded_manager(lang);
+            let args = fluent_args![
+                "label" => "RAM",
+                "value" => "50%",
+                "used" => "1.5",
+                "total" => "3.0",
+            ];
+            let menu = i18n.get_message_with_args("tray-menu-value-memory", &args);
+            let tooltip = i18n.get_message_with_args("tray-tooltip-memory", &args);
+            assert!(menu.ends_with(" GiB)"), "{}: {}", lang, menu);
+            assert!(tooltip.ends_with(" GiB"), "{}: {}", lang, tooltip);
+        }
     }
 }
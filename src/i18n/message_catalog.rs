@@ -0,0 +1,253 @@
+use crate::enums::{
+    chart_time_window_enum::ChartTimeWindowEnum, log_level_enum::LogLevelEnum,
+    monitor_type_enum::MonitorTypeEnum, process_action_enum::ProcessActionEnum,
+    process_metric_enum::ProcessMetricEnum,
+};
+use strum::IntoEnumIterator;
+
+/// A message the application looks up, with the arguments it passes to it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageSpec {
+    pub id: String,
+    pub args: &'static [&'static str],
+}
+
+/// Messages requested by literal ID. Keys that come from enums are collected
+/// in `expected_messages` instead.
+/// The tests below check this list against the lookups in the source.
+const STATIC_MESSAGES: &[(&str, &[&str])] = &[
+    // Main UI
+    ("settings-title", &[]),
+    ("refresh-time-label", &[]),
+    ("shutdown-button-label", &[]),
+    ("monitor-label-prefix", &[]),
+    ("language-name", &[]),
+    ("language-system-default", &["language"]),
+    ("language-label", &[]),
+    ("run-on-startup-label", &[]),
+    ("minimized-on-startup-label", &[]),
+    ("log-level-label", &[]),
+    ("view-logs-button", &[]),
+    // System tray
+    ("tray-settings-item", &[]),
+    ("tray-dashboard-item", &[]),
+    ("tray-monitors-submenu", &[]),
+    ("tray-pause-item", &[]),
+    ("tray-shutdown-item", &[]),
+    ("tray-menu-value", &["label", "value"]),
+    (
+        "tray-menu-value-memory",
+        &["label", "value", "used", "total"],
+    ),
+    (
+        "tray-menu-value-process-group",
+        &["label", "value", "count"],
+    ),
+    ("tray-tooltip-value", &["title", "value"]),
+    ("tray-tooltip-memory", &["used", "total"]),
+    (
+        "tray-tooltip-process-group-details",
+        &["cpu", "memory", "count"],
+    ),
+    ("tray-tooltip-statistics", &["min", "avg", "max", "minutes"]),
+    // Dashboard
+    ("dashboard-window-title", &[]),
+    ("dashboard-time-window-label", &[]),
+    ("dashboard-point-label", &["value", "seconds"]),
+    ("dashboard-no-data", &[]),
+    // Units
+    ("unit-percent", &["value"]),
+    ("unit-kilobytes", &["value"]),
+    ("unit-megabytes", &["value"]),
+    ("unit-gigabytes", &["value"]),
+    ("icon-value-kilobytes", &["value"]),
+    ("icon-value-megabytes", &["value"]),
+    ("icon-value-gigabytes", &["value"]),
+    // Processes
+    ("processes-button-label", &[]),
+    ("processes-window-title", &[]),
+    ("process-column-pid", &[]),
+    ("process-column-name", &[]),
+    ("process-column-cpu", &[]),
+    ("process-column-memory", &[]),
+    ("process-confirm-title", &[]),
+    ("process-nice-label", &[]),
+    ("process-confirm-button", &[]),
+    ("process-cancel-button", &[]),
+    ("process-error-permission-denied", &[]),
+    ("process-error-not-found", &[]),
+    ("process-error-unsupported", &[]),
+    ("process-error-generic", &[]),
+    ("process-pin-by-name", &[]),
+    ("process-pin-by-pid", &[]),
+    ("process-group-by-name", &[]),
+    ("process-monitor-remove-tooltip", &[]),
+    ("process-group-add-label", &[]),
+    ("process-group-pattern-hint", &[]),
+    ("process-group-command-line-label", &[]),
+    // Startup errors
+    ("startup-error-title", &[]),
+    ("startup-error-close-button", &[]),
+    ("config-error-message", &["path"]),
+    ("startup-error-message", &[]),
+    // Configuration file
+    ("config-conflict-message", &[]),
+    ("config-conflict-reload-button", &[]),
+    ("config-conflict-keep-button", &[]),
+    ("config-reload-error", &["details"]),
+    ("config-save-error", &["details"]),
+];
+
+/// Every message the application can request. Used by the `check-translations`
+/// subcommand, so new lookups need an entry here or in one of the enum key methods.
+pub fn expected_messages() -> Vec<MessageSpec> {
+    let monitor_types = MonitorTypeEnum::builtin().chain([
+        MonitorTypeEnum::PinnedProcess(0),
+        MonitorTypeEnum::ProcessGroup(0),
+    ]);
+    let process_actions = [
+        ProcessActionEnum::Terminate,
+        ProcessActionEnum::Kill,
+        ProcessActionEnum::Renice(0),
+    ];
+
+    let enum_keys = monitor_types
+        .flat_map(|monitor_type| {
+            [
+                monitor_type.icon_label_key(),
+                monitor_type.tray_tooltip_key(),
+            ]
+        })
+        .chain(
+            process_actions
+                .iter()
+                .flat_map(|action| [action.label_key(), action.confirm_key()]),
+        )
+        .chain(ChartTimeWindowEnum::iter().map(|time_window| time_window.label_key()))
+        .chain(ProcessMetricEnum::iter().map(|metric| metric.label_key()))
+        .chain(LogLevelEnum::iter().map(|level| level.label_key()));
+
+    STATIC_MESSAGES
+        .iter()
+        .map(|(id, args)| MessageSpec {
+            id: id.to_string(),
+            args,
+        })
+        .chain(enum_keys.map(|id| MessageSpec {
+            id: id.to_string(),
+            args: &[],
+        }))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n::{embedded_translations, i18n_manager::FALLBACK_LANGUAGE};
+    use fluent::FluentResource;
+    use fluent_syntax::ast::Entry;
+    use regex::Regex;
+    use std::collections::BTreeSet;
+    use std::path::Path;
+
+    /// Prefixes of IDs that `I18nManager::scale_bytes` builds at runtime, so
+    /// they never appear as literals.
+    const COMPOSED_ID_PREFIXES: &[&str] = &["unit-", "icon-value-"];
+
+    /// Contents of every Rust file under `src`, except this catalog, without
+    /// their test modules, whose lookups use made-up messages.
+    fn sources() -> Vec<String> {
+        fn collect(dir: &Path, sources: &mut Vec<String>) {
+            for entry in std::fs::read_dir(dir).unwrap().flatten() {
+                let path = entry.path();
+                if path.is_dir() {
+                    collect(&path, sources);
+                } else if path.extension().is_some_and(|ext| ext == "rs")
+                    && !path.ends_with("i18n/message_catalog.rs")
+                {
+                    let source = std::fs::read_to_string(&path).unwrap();
+                    let runtime = source.split("#[cfg(test)]\nmod tests").next().unwrap();
+                    sources.push(runtime.to_string());
+                }
+            }
+        }
+
+        let mut sources = Vec::new();
+        collect(
+            &Path::new(env!("CARGO_MANIFEST_DIR")).join("src"),
+            &mut sources,
+        );
+        sources
+    }
+
+    /// IDs passed literally to `get_message*`, or returned from a match arm
+    /// like the `*_key` methods do.
+    fn looked_up_ids(sources: &[String]) -> BTreeSet<String> {
+        let lookup = Regex::new(r#"get_message(?:_with_args)?\(\s*"([^"]+)""#).unwrap();
+        let key_arm = Regex::new(r#"=> "([a-z0-9]+(?:-[a-z0-9]+)+)""#).unwrap();
+        sources
+            .iter()
+            .flat_map(|source| {
+                lookup
+                    .captures_iter(source)
+                    .chain(key_arm.captures_iter(source))
+                    .map(|captures| captures[1].to_string())
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    fn english_message_ids() -> BTreeSet<String> {
+        let ftl = embedded_translations::get(FALLBACK_LANGUAGE).unwrap();
+        let resource = FluentResource::try_new(ftl.to_string()).unwrap();
+        resource
+            .entries()
+            .filter_map(|entry| match entry {
+                Entry::Message(message) => Some(message.id.name.to_string()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn every_looked_up_message_is_cataloged_and_translated() {
+        let cataloged: BTreeSet<String> = expected_messages()
+            .into_iter()
+            .map(|spec| spec.id)
+            .collect();
+        let translated = english_message_ids();
+        let looked_up = looked_up_ids(&sources());
+        assert!(looked_up.len() > 50, "source scan found too few lookups");
+
+        let uncataloged: Vec<_> = looked_up.difference(&cataloged).collect();
+        let untranslated: Vec<_> = looked_up.difference(&translated).collect();
+        assert!(
+            uncataloged.is_empty(),
+            "missing from STATIC_MESSAGES: {uncataloged:?}"
+        );
+        assert!(
+            untranslated.is_empty(),
+            "missing from {FALLBACK_LANGUAGE}: {untranslated:?}"
+        );
+    }
+
+    #[test]
+    fn every_cataloged_message_is_used() {
+        let sources = sources();
+        let unused: Vec<_> = STATIC_MESSAGES
+            .iter()
+            .map(|(id, _)| *id)
+            .filter(|id| {
+                !COMPOSED_ID_PREFIXES
+                    .iter()
+                    .any(|prefix| id.starts_with(prefix))
+            })
+            .filter(|id| {
+                let literal = format!("\"{id}\"");
+                !sources.iter().any(|source| source.contains(&literal))
+            })
+            .collect();
+
+        assert!(unused.is_empty(), "no longer looked up: {unused:?}");
+    }
+}
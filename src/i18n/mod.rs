@@ -1 +1,5 @@
+pub mod embedded_translations;
 pub mod i18n_manager;
+pub mod message_catalog;
+pub mod number_formatter;
+pub mod translation_check;
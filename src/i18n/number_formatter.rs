@@ -0,0 +1,56 @@
+use unic_langid::LanguageIdentifier;
+
+/// Languages that write decimals with a comma, e.g. "1,5" instead of "1.5".
+const COMMA_DECIMAL_LANGUAGES: &[&str] = &[
+    "bg", "cs", "da", "de", "el", "es", "fi", "fr", "hr", "hu", "id", "it", "lt", "lv", "nb", "nl",
+    "pl", "pt", "ro", "ru", "sk", "sl", "sr", "sv", "tr", "uk",
+];
+
+/// Formats plain numbers according to the conventions of the active language.
+#[derive(Debug, Clone, Copy)]
+pub struct NumberFormatter {
+    decimal_separator: char,
+}
+
+impl NumberFormatter {
+    pub fn for_language(lang_id: &LanguageIdentifier) -> Self {
+        let decimal_separator = if COMMA_DECIMAL_LANGUAGES.contains(&lang_id.language.as_str()) {
+            ','
+        } else {
+            '.'
+        };
+
+        Self { decimal_separator }
+    }
+
+    pub fn format_decimal(&self, value: f64, fraction_digits: usize) -> String {
+        let formatted = format!("{:.*}", fraction_digits, value);
+        if self.decimal_separator == '.' {
+            formatted
+        } else {
+            formatted.replace('.', &self.decimal_separator.to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn formatter(lang: &str) -> NumberFormatter {
+        NumberFormatter::for_language(&lang.parse().unwrap())
+    }
+
+    #[test]
+    fn uses_the_language_decimal_separator() {
+        assert_eq!(formatter("en-US").format_decimal(1.5, 1), "1.5");
+        assert_eq!(formatter("pl").format_decimal(1.5, 1), "1,5");
+        assert_eq!(formatter("pl-PL").format_decimal(1.25, 2), "1,25");
+    }
+
+    #[test]
+    fn rounds_to_the_requested_digits() {
+        assert_eq!(formatter("en-US").format_decimal(2.96, 1), "3.0");
+        assert_eq!(formatter("pl").format_decimal(42.4, 0), "42");
+    }
+}
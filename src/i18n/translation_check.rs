@@ -0,0 +1,221 @@
+use crate::config::app_paths;
+use crate::i18n::{
+    embedded_translations,
+    message_catalog::{self, MessageSpec},
+};
+use fluent::FluentResource;
+use fluent_syntax::ast::{Entry, Expression, InlineExpression, Pattern, PatternElement};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+
+/// Compares every translation file with the messages the application uses and
+/// prints the problems found. Returns `false` when any file has problems.
+pub fn run() -> bool {
+    let expected = message_catalog::expected_messages();
+    // (origin, contents, whether messages it lacks count as missing)
+    let mut sources: Vec<(String, String, bool)> = embedded_translations::language_ids()
+        .filter_map(|id| {
+            embedded_translations::get(id).map(|ftl| {
+                (
+                    format!("translations/{}/main.ftl", id),
+                    ftl.to_string(),
+                    true,
+                )
+            })
+        })
+        .collect();
+
+    if let Some(entries) =
+        app_paths::translations_override_dir().and_then(|dir| fs::read_dir(dir).ok())
+    {
+        for entry in entries.flatten() {
+            let path = entry.path().join("main.ftl");
+            // Overrides of a bundled language only need the messages they change.
+            let is_complete = entry
+                .file_name()
+                .to_str()
+                .is_none_or(|id| embedded_translations::get(id).is_none());
+            match fs::read_to_string(&path) {
+                Ok(ftl) => sources.push((path.display().to_string(), ftl, is_complete)),
+                Err(e) if path.exists() => println!("{}: failed to read: {}", path.display(), e),
+                Err(_) => {}
+            }
+        }
+    }
+
+    let mut problem_count = 0;
+    for (origin, ftl, is_complete) in &sources {
+        let problems = check_source(ftl, &expected, *is_complete);
+        if problems.is_empty() {
+            println!("{}: OK", origin);
+        } else {
+            println!("{}:", origin);
+            for problem in &problems {
+                println!("  {}", problem);
+            }
+            problem_count += problems.len();
+        }
+    }
+
+    println!(
+        "Checked {} file(s) against {} message(s), {} problem(s) found.",
+        sources.len(),
+        expected.len(),
+        problem_count
+    );
+    problem_count == 0
+}
+
+/// Messages missing from `ftl` are only reported when it `is_complete`, i.e.
+/// not layered on top of a bundled file.
+fn check_source(ftl: &str, expected: &[MessageSpec], is_complete: bool) -> Vec<String> {
+    let mut problems = Vec::new();
+    let resource = FluentResource::try_new(ftl.to_string()).unwrap_or_else(|(resource, errors)| {
+        for error in errors {
+            problems.push(format!("syntax error: {:?}", error));
+        }
+        resource
+    });
+
+    // Message ID -> variables referenced by its value and attributes.
+    let mut messages: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+    for entry in resource.entries() {
+        if let Entry::Message(message) = entry {
+            let mut variables = BTreeSet::new();
+            if let Some(value) = &message.value {
+                collect_pattern_variables(value, &mut variables);
+            }
+            for attribute in &message.attributes {
+                collect_pattern_variables(&attribute.value, &mut variables);
+            }
+            messages.insert(message.id.name, variables);
+        }
+    }
+
+    for spec in expected {
+        let Some(variables) = messages.get(spec.id.as_str()) else {
+            if is_complete {
+                problems.push(format!("missing message '{}'", spec.id));
+            }
+            continue;
+        };
+        for variable in variables {
+            if !spec.args.contains(variable) {
+                problems.push(format!(
+                    "message '{}' uses unknown argument ${} (available: {})",
+                    spec.id,
+                    variable,
+                    format_args_list(spec.args)
+                ));
+            }
+        }
+    }
+
+    for id in messages.keys() {
+        if !expected.iter().any(|spec| spec.id == *id) {
+            problems.push(format!("extra message '{}' is never used", id));
+        }
+    }
+
+    problems
+}
+
+fn format_args_list(args: &[&str]) -> String {
+    if args.is_empty() {
+        return "none".to_string();
+    }
+    args.iter()
+        .map(|arg| format!("${}", arg))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn collect_pattern_variables<'s>(pattern: &Pattern<&'s str>, variables: &mut BTreeSet<&'s str>) {
+    for element in &pattern.elements {
+        if let PatternElement::Placeable { expression } = element {
+            collect_expression_variables(expression, variables);
+        }
+    }
+}
+
+fn collect_expression_variables<'s>(
+    expression: &Expression<&'s str>,
+    variables: &mut BTreeSet<&'s str>,
+) {
+    match expression {
+        Expression::Select { selector, variants } => {
+            collect_inline_variables(selector, variables);
+            for variant in variants {
+                collect_pattern_variables(&variant.value, variables);
+            }
+        }
+        Expression::Inline(inline) => collect_inline_variables(inline, variables),
+    }
+}
+
+fn collect_inline_variables<'s>(
+    inline: &InlineExpression<&'s str>,
+    variables: &mut BTreeSet<&'s str>,
+) {
+    match inline {
+        InlineExpression::VariableReference { id } => {
+            variables.insert(id.name);
+        }
+        InlineExpression::FunctionReference { arguments, .. } => {
+            for argument in &arguments.positional {
+                collect_inline_variables(argument, variables);
+            }
+            for argument in &arguments.named {
+                collect_inline_variables(&argument.value, variables);
+            }
+        }
+        InlineExpression::Placeable { expression } => {
+            collect_expression_variables(expression, variables)
+        }
+        // Variables inside term arguments belong to the term call, and message
+        // references are checked on their own.
+        InlineExpression::StringLiteral { .. }
+        | InlineExpression::NumberLiteral { .. }
+        | InlineExpression::MessageReference { .. }
+        | InlineExpression::TermReference { .. } => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expected() -> Vec<MessageSpec> {
+        vec![
+            MessageSpec {
+                id: "greeting".to_string(),
+                args: &["name"],
+            },
+            MessageSpec {
+                id: "farewell".to_string(),
+                args: &[],
+            },
+        ]
+    }
+
+    #[test]
+    fn complete_file_reports_missing_messages() {
+        let problems = check_source("greeting = Hi { $name }\n", &expected(), true);
+
+        assert_eq!(problems, ["missing message 'farewell'"]);
+    }
+
+    #[test]
+    fn override_only_reports_what_it_gets_wrong() {
+        let ftl = "greeting = Hi { $user }\nunused = Never shown\n";
+        let problems = check_source(ftl, &expected(), false);
+
+        assert_eq!(
+            problems,
+            [
+                "message 'greeting' uses unknown argument $user (available: $name)",
+                "extra message 'unused' is never used",
+            ]
+        );
+    }
+}
@@ -0,0 +1,40 @@
+use crate::ipc::{ipc_endpoint, ipc_server::USAGE};
+use std::io::{self, BufRead, BufReader, Write};
+use std::time::Duration;
+
+const RESPONSE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Sends one command to the running instance and returns its response line.
+pub fn send(command: &str) -> io::Result<String> {
+    let stream = ipc_endpoint::connect()?;
+    ipc_endpoint::set_read_timeout(&stream, RESPONSE_TIMEOUT)?;
+    writeln!(&stream, "{}", command)?;
+
+    let mut response = String::new();
+    BufReader::new(&stream).read_line(&mut response)?;
+    Ok(response.trim_end().to_string())
+}
+
+/// Runs the `ctl` subcommand: sends the arguments as one command and prints the
+/// reply. Returns `false` when the command could not be delivered or failed.
+pub fn run(args: &[String]) -> bool {
+    if args.is_empty() {
+        eprintln!("Usage: rust_system_monitor ctl <{}>", USAGE);
+        return false;
+    }
+
+    match send(&args.join(" ")) {
+        Ok(response) if response.starts_with("error:") => {
+            eprintln!("{}", response);
+            false
+        }
+        Ok(response) => {
+            println!("{}", response);
+            true
+        }
+        Err(e) => {
+            eprintln!("Failed to reach the running instance: {}", e);
+            false
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use crate::config::app_paths;
+#[cfg(windows)]
+use crate::ipc::user_sid;
+use interprocess::local_socket::prelude::*;
+use interprocess::local_socket::{
+    GenericFilePath, Listener, ListenerNonblockingMode, ListenerOptions, Name,
+};
+#[cfg(unix)]
+use std::fs;
+use std::io;
+use std::time::Duration;
+
+pub type IpcStream = interprocess::local_socket::Stream;
+
+/// Listening side of the per-user command endpoint: a Unix domain socket only
+/// the current user can connect to, or a named pipe only they can open on Windows.
+pub struct IpcListener {
+    inner: Listener,
+}
+
+impl IpcListener {
+    /// Fails with `AddrInUse` when the socket exists, see `InstanceLock::listen`.
+    /// The socket is removed again when the listener is dropped.
+    #[cfg(unix)]
+    pub fn bind() -> io::Result<Self> {
+        use interprocess::os::unix::local_socket::ListenerOptionsExt;
+
+        let path = app_paths::ipc_socket_path();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        // The mode is applied before binding, so the socket is never reachable
+        // by other users. Platforms that can't do that fail with `Unsupported`.
+        let inner = ListenerOptions::new()
+            .name(endpoint_name()?)
+            .mode(0o600)
+            .create_sync()?;
+        Ok(Self { inner })
+    }
+
+    /// Fails with `AddrInUse` when the pipe is taken. Pipes disappear with the
+    /// process that created them, so they are never left behind.
+    #[cfg(windows)]
+    pub fn bind() -> io::Result<Self> {
+        use interprocess::os::windows::local_socket::ListenerOptionsExt;
+        use interprocess::os::windows::security_descriptor::SecurityDescriptor;
+
+        // Grants full access to the current user and nobody else.
+        let sddl = format!("D:P(A;;GA;;;{})", user_sid::current_user_sid()?);
+        let sddl = widestring::U16CString::from_str(sddl).map_err(io::Error::other)?;
+        let inner = ListenerOptions::new()
+            .name(endpoint_name()?)
+            .security_descriptor(SecurityDescriptor::deserialize(&sddl)?)
+            .create_sync()?;
+        Ok(Self { inner })
+    }
+
+    /// Only `accept` becomes nonblocking, accepted streams still block.
+    pub fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
+        self.inner
+            .set_nonblocking(ListenerNonblockingMode::from_bool(nonblocking, false))
+    }
+
+    pub fn accept(&self) -> io::Result<IpcStream> {
+        self.inner.accept()
+    }
+}
+
+pub fn connect() -> io::Result<IpcStream> {
+    IpcStream::connect(endpoint_name()?)
+}
+
+/// Limits how long reads on the stream block. Named pipes don't support read
+/// timeouts, so on Windows reads block until the other side writes or closes.
+pub fn set_read_timeout(stream: &IpcStream, timeout: Duration) -> io::Result<()> {
+    match stream.set_recv_timeout(Some(timeout)) {
+        Err(e) if e.kind() == io::ErrorKind::Unsupported => Ok(()),
+        result => result,
+    }
+}
+
+/// Deletes the socket, e.g. one left behind by a crash.
+#[cfg(unix)]
+pub fn remove_endpoint() -> io::Result<()> {
+    fs::remove_file(app_paths::ipc_socket_path())
+}
+
+#[cfg(unix)]
+fn endpoint_name() -> io::Result<Name<'static>> {
+    app_paths::ipc_socket_path().to_fs_name::<GenericFilePath>()
+}
+
+#[cfg(windows)]
+fn endpoint_name() -> io::Result<Name<'static>> {
+    app_paths::ipc_pipe_name(&user_sid::current_user_sid()?).to_fs_name::<GenericFilePath>()
+}
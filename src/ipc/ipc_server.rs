@@ -0,0 +1,236 @@
+use crate::enums::monitor_type_enum::MonitorTypeEnum;
+use crate::enums::ui_command_enum::UICommandEnum;
+use crate::error::app_error::{AppError, Result};
+use crate::ipc::ipc_endpoint::{self, IpcListener, IpcStream};
+use crossbeam_channel::{RecvTimeoutError, Sender};
+use std::io::{self, BufRead, BufReader, Write};
+use std::sync::{Arc, mpsc};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+/// Answers commands sent to the endpoint by other processes and forwards them
+/// to the UI, like the tray menu does.
+pub struct IpcServer {
+    shutdown_tx: Option<Sender<()>>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl IpcServer {
+    const POLL_INTERVAL: Duration = Duration::from_millis(100);
+    const READ_TIMEOUT: Duration = Duration::from_secs(1);
+    /// How long `get-stats` waits for the UI thread to answer.
+    const REPLY_TIMEOUT: Duration = Duration::from_secs(2);
+
+    /// `on_command` runs on a connection thread after each forwarded command,
+    /// e.g. to wake up the UI.
+    pub fn spawn(
+        listener: IpcListener,
+        command_tx: mpsc::Sender<UICommandEnum>,
+        on_command: impl Fn() + Send + Sync + 'static,
+    ) -> Result<Self> {
+        listener.set_nonblocking(true).map_err(AppError::Ipc)?;
+        let on_command = Arc::new(on_command);
+        let (shutdown_tx, shutdown_rx) = crossbeam_channel::bounded(1);
+
+        let handle = thread::Builder::new()
+            .name("ipc-server".to_string())
+            .spawn(move || {
+                while let Err(RecvTimeoutError::Timeout) =
+                    shutdown_rx.recv_timeout(Self::POLL_INTERVAL)
+                {
+                    loop {
+                        match listener.accept() {
+                            Ok(stream) => {
+                                spawn_connection(stream, command_tx.clone(), on_command.clone())
+                            }
+                            Err(e) if e.kind() == io::ErrorKind::WouldBlock => break,
+                            Err(e) => {
+                                tracing::warn!(error = %e, "Failed to accept IPC connection");
+                                break;
+                            }
+                        }
+                    }
+                }
+            })
+            .map_err(|source| AppError::ThreadSpawn {
+                name: "ipc-server".to_string(),
+                source,
+            })?;
+
+        Ok(Self {
+            shutdown_tx: Some(shutdown_tx),
+            handle: Some(handle),
+        })
+    }
+}
+
+impl Drop for IpcServer {
+    fn drop(&mut self) {
+        if let Some(shutdown_tx) = self.shutdown_tx.take() {
+            let _ = shutdown_tx.send(());
+        }
+
+        if let Some(handle) = self.handle.take()
+            && let Err(e) = handle.join()
+        {
+            tracing::error!(panic = ?e, "IPC server thread panicked during shutdown");
+        }
+    }
+}
+
+/// Handles the connection on its own thread, so a client that never sends a
+/// command can't hold up the others where reads have no timeout.
+fn spawn_connection(
+    stream: IpcStream,
+    command_tx: mpsc::Sender<UICommandEnum>,
+    on_command: Arc<impl Fn() + Send + Sync + 'static>,
+) {
+    let result = thread::Builder::new()
+        .name("ipc-connection".to_string())
+        .spawn(move || {
+            if let Err(e) = handle_connection(stream, &command_tx, &*on_command) {
+                tracing::debug!(error = %e, "IPC connection failed");
+            }
+        });
+    if let Err(e) = result {
+        tracing::warn!(error = %e, "Failed to spawn IPC connection thread");
+    }
+}
+
+/// Reads one command line and writes one response line. Connections closed
+/// without a command are ignored.
+fn handle_connection(
+    stream: IpcStream,
+    command_tx: &mpsc::Sender<UICommandEnum>,
+    on_command: &impl Fn(),
+) -> io::Result<()> {
+    ipc_endpoint::set_read_timeout(&stream, IpcServer::READ_TIMEOUT)?;
+    let mut reader = BufReader::new(&stream);
+    let mut line = String::new();
+    if reader.read_line(&mut line)? == 0 {
+        return Ok(());
+    }
+
+    let line = line.trim();
+    tracing::debug!(command = line, "Received IPC command");
+    let words: Vec<&str> = line.split_whitespace().collect();
+    let response = if words == ["get-stats"] {
+        let (reply_tx, reply_rx) = mpsc::channel();
+        forward(UICommandEnum::GetStats(reply_tx), command_tx, on_command)?;
+        reply_rx
+            .recv_timeout(IpcServer::REPLY_TIMEOUT)
+            .unwrap_or_else(|_| "error: the application did not respond".to_string())
+    } else {
+        match parse_command(&words) {
+            Some(command) => {
+                forward(command, command_tx, on_command)?;
+                "ok".to_string()
+            }
+            None => format!("error: unknown command '{}', expected {}", line, USAGE),
+        }
+    };
+
+    writeln!(&stream, "{}", response)
+}
+
+fn forward(
+    command: UICommandEnum,
+    command_tx: &mpsc::Sender<UICommandEnum>,
+    on_command: &impl Fn(),
+) -> io::Result<()> {
+    command_tx
+        .send(command)
+        .map_err(|_| io::Error::from(io::ErrorKind::BrokenPipe))?;
+    on_command();
+    Ok(())
+}
+
+/// Commands accepted by the endpoint, shown in error replies and the CLI help.
+pub const USAGE: &str =
+    "show-settings | quit | pause | resume | set-monitor <monitor> on|off | get-stats";
+
+/// Parses every command except `get-stats`, which needs a reply channel.
+fn parse_command(words: &[&str]) -> Option<UICommandEnum> {
+    match words {
+        ["show-settings"] => Some(UICommandEnum::ShowSettings),
+        ["quit"] => Some(UICommandEnum::Quit),
+        ["pause"] => Some(UICommandEnum::SetPaused(true)),
+        ["resume"] => Some(UICommandEnum::SetPaused(false)),
+        ["set-monitor", monitor, state] => {
+            let monitor_type = MonitorTypeEnum::from_id(monitor)?;
+            let active = match *state {
+                "on" => true,
+                "off" => false,
+                _ => return None,
+            };
+            Some(UICommandEnum::SetMonitorActive(monitor_type, active))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_every_command() {
+        assert!(matches!(
+            parse_command(&["show-settings"]),
+            Some(UICommandEnum::ShowSettings)
+        ));
+        assert!(matches!(
+            parse_command(&["quit"]),
+            Some(UICommandEnum::Quit)
+        ));
+        assert!(matches!(
+            parse_command(&["pause"]),
+            Some(UICommandEnum::SetPaused(true))
+        ));
+        assert!(matches!(
+            parse_command(&["resume"]),
+            Some(UICommandEnum::SetPaused(false))
+        ));
+        assert!(matches!(
+            parse_command(&["set-monitor", "cpu", "on"]),
+            Some(UICommandEnum::SetMonitorActive(
+                MonitorTypeEnum::CpuUsage,
+                true
+            ))
+        ));
+        assert!(matches!(
+            parse_command(&["set-monitor", "group-3", "off"]),
+            Some(UICommandEnum::SetMonitorActive(
+                MonitorTypeEnum::ProcessGroup(3),
+                false
+            ))
+        ));
+    }
+
+    #[test]
+    fn rejects_bad_arity() {
+        for words in [
+            &[][..],
+            &["quit", "now"],
+            &["set-monitor"],
+            &["set-monitor", "cpu"],
+            &["set-monitor", "cpu", "on", "now"],
+        ] {
+            assert!(parse_command(words).is_none(), "{:?}", words);
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_commands_states_and_monitors() {
+        for words in [
+            &["stop"][..],
+            &["get-stats"],
+            &["set-monitor", "cpu", "yes"],
+            &["set-monitor", "cpu", "ON"],
+            &["set-monitor", "gpu", "on"],
+            &["set-monitor", "pinned-x", "off"],
+        ] {
+            assert!(parse_command(words).is_none(), "{:?}", words);
+        }
+    }
+}
@@ -0,0 +1,7 @@
+pub mod ipc_client;
+pub mod ipc_endpoint;
+pub mod ipc_server;
+pub mod single_instance;
+pub mod stats_snapshot;
+#[cfg(windows)]
+pub mod user_sid;
@@ -0,0 +1,94 @@
+use crate::config::app_paths;
+#[cfg(unix)]
+use crate::ipc::ipc_endpoint;
+use crate::ipc::ipc_endpoint::IpcListener;
+#[cfg(windows)]
+use crate::ipc::user_sid;
+use std::io;
+
+/// Held by the running instance until it exits. Launches that find it taken
+/// hand over to that instance instead of starting.
+pub struct InstanceLock {
+    #[cfg(unix)]
+    _file: std::fs::File,
+    #[cfg(windows)]
+    mutex: windows_sys::Win32::Foundation::HANDLE,
+}
+
+/// Takes the per-user instance lock. Returns `None` when another instance
+/// holds it.
+#[cfg(unix)]
+pub fn acquire() -> io::Result<Option<InstanceLock>> {
+    use std::os::fd::AsRawFd;
+    use std::os::unix::fs::OpenOptionsExt;
+
+    let path = app_paths::instance_lock_path();
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let file = std::fs::OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .mode(0o600)
+        .custom_flags(libc::O_NOFOLLOW)
+        .open(&path)?;
+
+    // The lock is released by the kernel when the process exits, even after a crash.
+    // SAFETY: the descriptor stays open for the duration of the call.
+    if unsafe { libc::flock(file.as_raw_fd(), libc::LOCK_EX | libc::LOCK_NB) } != 0 {
+        let error = io::Error::last_os_error();
+        return if error.kind() == io::ErrorKind::WouldBlock {
+            Ok(None)
+        } else {
+            Err(error)
+        };
+    }
+    Ok(Some(InstanceLock { _file: file }))
+}
+
+#[cfg(windows)]
+pub fn acquire() -> io::Result<Option<InstanceLock>> {
+    use windows_sys::Win32::Foundation::{CloseHandle, ERROR_ALREADY_EXISTS, GetLastError};
+    use windows_sys::Win32::System::Threading::CreateMutexW;
+
+    let name: Vec<u16> = app_paths::instance_mutex_name(&user_sid::current_user_sid()?)
+        .encode_utf16()
+        .chain(Some(0))
+        .collect();
+    // SAFETY: the name is null-terminated and null attributes are allowed.
+    let mutex = unsafe { CreateMutexW(std::ptr::null(), 0, name.as_ptr()) };
+    if mutex.is_null() {
+        return Err(io::Error::last_os_error());
+    }
+    // SAFETY: `GetLastError` has no preconditions, and the handle is owned here.
+    unsafe {
+        if GetLastError() == ERROR_ALREADY_EXISTS {
+            CloseHandle(mutex);
+            return Ok(None);
+        }
+    }
+    Ok(Some(InstanceLock { mutex }))
+}
+
+impl InstanceLock {
+    /// Opens the command endpoint. No other instance listens while the lock is
+    /// held, so a socket left behind by a crash can be replaced safely.
+    pub fn listen(&self) -> io::Result<IpcListener> {
+        #[cfg(unix)]
+        match ipc_endpoint::remove_endpoint() {
+            Ok(()) => tracing::info!("Removed endpoint left behind by a previous instance"),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e),
+        }
+        IpcListener::bind()
+    }
+}
+
+#[cfg(windows)]
+impl Drop for InstanceLock {
+    fn drop(&mut self) {
+        // SAFETY: the handle is owned and not used after this.
+        unsafe { windows_sys::Win32::Foundation::CloseHandle(self.mutex) };
+    }
+}
@@ -0,0 +1,52 @@
+use crate::config::app_config::AppConfig;
+use crate::enums::monitor_unit_enum::MonitorUnitEnum;
+use crate::i18n::i18n_manager::I18nManager;
+use crate::monitor::monitor::MonitorSample;
+use serde::Serialize;
+
+/// Reply to the `get-stats` command.
+#[derive(Serialize, Debug)]
+pub struct StatsSnapshot {
+    pub paused: bool,
+    pub monitors: Vec<MonitorStats>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct MonitorStats {
+    pub id: String,
+    pub label: String,
+    pub active: bool,
+    /// Missing until the monitor has been sampled.
+    pub value: Option<f32>,
+    pub unit: Option<MonitorUnitEnum>,
+}
+
+impl StatsSnapshot {
+    pub fn new(app_config: &AppConfig, i18n: &I18nManager, samples: &[MonitorSample]) -> Self {
+        let monitors = app_config
+            .monitor_types()
+            .into_iter()
+            .map(|monitor_type| {
+                let sample = samples
+                    .iter()
+                    .find(|sample| sample.monitor_type == monitor_type);
+                MonitorStats {
+                    id: monitor_type.id(),
+                    label: monitor_type.icon_label(i18n, app_config),
+                    active: app_config.is_monitor_active(monitor_type),
+                    value: sample.map(|sample| sample.value),
+                    unit: sample.map(|sample| sample.unit),
+                }
+            })
+            .collect();
+
+        Self {
+            paused: app_config.general.sampling_paused,
+            monitors,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_else(|e| format!("error: {}", e))
+    }
+}
@@ -0,0 +1,63 @@
+use std::io;
+use std::os::windows::io::{AsRawHandle, FromRawHandle, OwnedHandle};
+use std::ptr;
+use windows_sys::Win32::Foundation::LocalFree;
+use windows_sys::Win32::Security::Authorization::ConvertSidToStringSidW;
+use windows_sys::Win32::Security::{GetTokenInformation, TOKEN_QUERY, TOKEN_USER, TokenUser};
+use windows_sys::Win32::System::Threading::{GetCurrentProcess, OpenProcessToken};
+
+fn check(result: i32) -> io::Result<()> {
+    if result == 0 {
+        Err(io::Error::last_os_error())
+    } else {
+        Ok(())
+    }
+}
+
+/// String form of the current user's SID, e.g. `S-1-5-21-...`.
+pub fn current_user_sid() -> io::Result<String> {
+    let mut token = ptr::null_mut();
+    // SAFETY: `token` receives a handle owned by the guard below.
+    check(unsafe { OpenProcessToken(GetCurrentProcess(), TOKEN_QUERY, &mut token) })?;
+    // SAFETY: the handle was just opened and nothing else owns it.
+    let token = unsafe { OwnedHandle::from_raw_handle(token) };
+
+    let mut size = 0;
+    // SAFETY: a null buffer only queries the required size.
+    unsafe {
+        GetTokenInformation(
+            token.as_raw_handle(),
+            TokenUser,
+            ptr::null_mut(),
+            0,
+            &mut size,
+        )
+    };
+    // u64 elements keep the buffer aligned for `TOKEN_USER`.
+    let mut buffer = vec![0u64; (size as usize).div_ceil(8)];
+    // SAFETY: the buffer holds at least `size` bytes.
+    check(unsafe {
+        GetTokenInformation(
+            token.as_raw_handle(),
+            TokenUser,
+            buffer.as_mut_ptr().cast(),
+            size,
+            &mut size,
+        )
+    })?;
+
+    let mut sid_string = ptr::null_mut();
+    // SAFETY: the buffer now starts with a `TOKEN_USER` whose SID points into it.
+    check(unsafe {
+        let user = &*buffer.as_ptr().cast::<TOKEN_USER>();
+        ConvertSidToStringSidW(user.User.Sid, &mut sid_string)
+    })?;
+    // SAFETY: the string is null-terminated and freed with `LocalFree` once copied.
+    let sid = unsafe {
+        let len = (0..).take_while(|&i| *sid_string.add(i) != 0).count();
+        let sid = String::from_utf16_lossy(std::slice::from_raw_parts(sid_string, len));
+        LocalFree(sid_string.cast());
+        sid
+    };
+    Ok(sid)
+}
@@ -0,0 +1,138 @@
+use crate::enums::log_level_enum::LogLevelEnum;
+use std::backtrace::Backtrace;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+use tracing_appender::non_blocking::WorkerGuard;
+use tracing_appender::rolling::{InitError, RollingFileAppender, Rotation};
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{EnvFilter, Registry, fmt, reload};
+
+const LOG_FILE_PREFIX: &str = "rust_system_monitor";
+/// Daily log files kept before the oldest one is deleted.
+const MAX_LOG_FILES: usize = 7;
+
+/// Handle to the global logger, used to change the level at runtime and to
+/// open the log directory.
+#[derive(Clone)]
+pub struct Logger {
+    filter: reload::Handle<EnvFilter, Registry>,
+    log_dir: Option<PathBuf>,
+    /// Set when `RUST_LOG` is present, which takes precedence over the config.
+    env_override: bool,
+}
+
+impl Logger {
+    /// Installs the global subscriber, logging to stderr and to a daily rotated
+    /// file in `log_dir`. The returned guard flushes the file when dropped, so it
+    /// must live until the process exits.
+    pub fn init(log_dir: Option<PathBuf>) -> (Self, Option<WorkerGuard>) {
+        let env_filter = EnvFilter::try_from_default_env().ok();
+        let env_override = env_filter.is_some();
+        let (filter_layer, filter) =
+            reload::Layer::new(env_filter.unwrap_or_else(|| level_filter(LogLevelEnum::default())));
+
+        let (file_appender, file_error) = match log_dir.as_deref().map(file_appender) {
+            Some(Ok(appender)) => (Some(appender), None),
+            Some(Err(e)) => (None, Some(e)),
+            None => (None, None),
+        };
+        let (file_writer, guard) = file_appender.map(tracing_appender::non_blocking).unzip();
+
+        let init_result = tracing_subscriber::registry()
+            .with(filter_layer)
+            .with(fmt::layer().with_writer(std::io::stderr))
+            .with(file_writer.map(|writer| fmt::layer().with_writer(writer).with_ansi(false)))
+            .try_init();
+        if let Err(e) = init_result {
+            eprintln!("Failed to install logger: {}", e);
+        }
+
+        if let Some(e) = file_error {
+            tracing::warn!(error = %e, "Log file could not be opened, logging to stderr only");
+        }
+        install_panic_hook();
+
+        let log_dir = log_dir.filter(|_| guard.is_some());
+        (
+            Self {
+                filter,
+                log_dir,
+                env_override,
+            },
+            guard,
+        )
+    }
+
+    pub fn set_level(&self, level: LogLevelEnum) {
+        if self.env_override {
+            return;
+        }
+        if let Err(e) = self.filter.reload(level_filter(level)) {
+            tracing::warn!(error = %e, "Failed to change log level");
+        }
+    }
+
+    pub fn log_dir(&self) -> Option<&Path> {
+        self.log_dir.as_deref()
+    }
+
+    /// Opens the log directory in the platform file manager.
+    pub fn open_log_dir(&self) {
+        let Some(dir) = self.log_dir() else {
+            tracing::warn!("No log directory to open");
+            return;
+        };
+
+        let program = if cfg!(target_os = "windows") {
+            "explorer"
+        } else if cfg!(target_os = "macos") {
+            "open"
+        } else {
+            "xdg-open"
+        };
+
+        match Command::new(program).arg(dir).spawn() {
+            // Reap the child in the background so it doesn't linger as a zombie.
+            Ok(mut child) => {
+                thread::spawn(move || child.wait());
+            }
+            Err(e) => {
+                tracing::warn!(dir = %dir.display(), error = %e, "Failed to open log directory")
+            }
+        }
+    }
+}
+
+fn file_appender(dir: &Path) -> Result<RollingFileAppender, InitError> {
+    RollingFileAppender::builder()
+        .rotation(Rotation::DAILY)
+        .filename_prefix(LOG_FILE_PREFIX)
+        .filename_suffix("log")
+        .max_log_files(MAX_LOG_FILES)
+        .build(dir)
+}
+
+/// Applies `level` to this crate. Dependencies only log warnings and errors,
+/// since windowing crates are very chatty below that.
+fn level_filter(level: LogLevelEnum) -> EnvFilter {
+    let dependency_level = match level {
+        LogLevelEnum::Error => "error",
+        _ => "warn",
+    };
+    EnvFilter::new(format!(
+        "{},{}={}",
+        dependency_level,
+        env!("CARGO_CRATE_NAME"),
+        level.as_str()
+    ))
+}
+
+/// Routes panics through the logger, so they end up in the log file when the
+/// app runs without a console.
+fn install_panic_hook() {
+    std::panic::set_hook(Box::new(|info| {
+        tracing::error!(backtrace = %Backtrace::capture(), "{}", info);
+    }));
+}
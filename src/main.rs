@@ -5,45 +5,193 @@ mod config;
 mod enums;
 mod error;
 mod i18n;
+mod ipc;
+mod logging;
 mod monitor;
+mod process;
 mod tray;
 mod ui;
 
 use app::App;
-use auto_launch::AutoLaunch;
+use auto_launch::AutoLaunchBuilder;
 use eframe::{NativeOptions, egui};
+use fluent::fluent_args;
 use i18n::i18n_manager::I18nManager;
+use ipc::ipc_endpoint::IpcListener;
+use logging::logger::Logger;
+use monitor::monitor::SystemMonitor;
+use std::io;
+use std::path::PathBuf;
+use std::process::ExitCode;
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
-use crate::config::app_config::AppConfig;
+use crate::config::{app_config::AppConfig, app_paths};
+use crate::enums::language_selection_enum::LanguageSelectionEnum;
+use crate::enums::metric_source_enum::MetricSourceEnum;
+use crate::error::app_error::{AppError, Result};
 
-// fn get_system_language() -> SupportedLanguageEnum {
-//     let system_locale = Locale::current();
-//     if system_locale.to_string().starts_with("pl") {
-//         return SupportedLanguageEnum::Polish;
-//     }
-//     SupportedLanguageEnum::English
-// }
+/// Reads the value of `--<name> <value>` (or `--<name>=<value>`) from the command line.
+fn arg_value(name: &str) -> Option<String> {
+    let flag = format!("--{name}");
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == flag {
+            return args.next();
+        }
+        if let Some(value) = arg
+            .strip_prefix(&flag)
+            .and_then(|rest| rest.strip_prefix('='))
+        {
+            return Some(value.to_string());
+        }
+    }
+    None
+}
+
+/// Reads the `--record <path>` or `--replay <path> [--replay-speed <factor>]` options.
+fn metric_source_arg() -> Result<MetricSourceEnum> {
+    match (arg_value("record"), arg_value("replay")) {
+        (None, None) if arg_value("replay-speed").is_some() => Err(AppError::InvalidArgument(
+            "--replay-speed requires --replay".to_string(),
+        )),
+        (None, None) => Ok(MetricSourceEnum::Live),
+        (Some(path), None) => Ok(MetricSourceEnum::Record(PathBuf::from(path))),
+        (None, Some(path)) => {
+            let speed = match arg_value("replay-speed") {
+                Some(speed) => speed
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|speed| speed.is_finite() && *speed > 0.0)
+                    .ok_or_else(|| {
+                        AppError::InvalidArgument(format!(
+                            "--replay-speed must be a positive number, got '{speed}'"
+                        ))
+                    })?,
+                None => 1.0,
+            };
+            Ok(MetricSourceEnum::Replay {
+                path: PathBuf::from(path),
+                speed,
+            })
+        }
+        (Some(_), Some(_)) => Err(AppError::InvalidArgument(
+            "--record and --replay can't be used together".to_string(),
+        )),
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().collect();
+    #[cfg(windows)]
+    if matches!(
+        args.get(1).map(String::as_str),
+        Some("check-translations" | "ctl")
+    ) {
+        attach_parent_console();
+    }
+    let subcommand_result = match args.get(1).map(String::as_str) {
+        Some("check-translations") => Some(i18n::translation_check::run()),
+        Some("ctl") => Some(ipc::ipc_client::run(&args[2..])),
+        _ => None,
+    };
+    if let Some(passed) = subcommand_result {
+        return if passed {
+            ExitCode::SUCCESS
+        } else {
+            ExitCode::FAILURE
+        };
+    }
+
+    // Kept until the end of `main` so buffered log lines are flushed on exit.
+    let (logger, _log_guard) = Logger::init(app_paths::log_dir());
+
+    // Parsed before the instance check, so invalid options are reported even
+    // when another instance is running.
+    let metric_source = match metric_source_arg() {
+        Ok(metric_source) => metric_source,
+        Err(e) => {
+            report_startup_error(&e, |i18n| i18n.get_message("startup-error-message"));
+            return ExitCode::FAILURE;
+        }
+    };
+
+    // Held until the end of `main`, so no other instance starts meanwhile.
+    let (_instance_lock, ipc_listener) = match ipc::single_instance::acquire() {
+        Ok(Some(instance_lock)) => {
+            let ipc_listener = instance_lock
+                .listen()
+                .inspect_err(|e| tracing::warn!(error = %e, "Failed to open the command endpoint"))
+                .ok();
+            (Some(instance_lock), ipc_listener)
+        }
+        Ok(None) if metric_source != MetricSourceEnum::Live => {
+            let error = AppError::InvalidArgument(
+                "already running, --record and --replay only apply to a new instance".to_string(),
+            );
+            report_startup_error(&error, |i18n| i18n.get_message("startup-error-message"));
+            return ExitCode::FAILURE;
+        }
+        Ok(None) => return activate_running_instance(),
+        Err(e) => {
+            tracing::warn!(error = %e, "Single-instance check failed, starting anyway");
+            (None, None)
+        }
+    };
+
+    let config_path = arg_value("config").map(PathBuf::from).unwrap_or_else(|| {
+        let path = app_paths::config_file();
+        AppConfig::import_legacy(&path);
+        path
+    });
+    let app_config = match AppConfig::new(&config_path) {
+        Ok(app_config) => app_config,
+        Err(e) => {
+            report_startup_error(&e, |i18n| {
+                i18n.get_message_with_args(
+                    "config-error-message",
+                    &fluent_args!["path" => config_path.display().to_string()],
+                )
+            });
+            return ExitCode::FAILURE;
+        }
+    };
+    logger.set_level(app_config.logging.level);
 
-fn main() -> std::result::Result<(), eframe::Error> {
-    let app_config = AppConfig::new().expect("Failed to load config.toml");
-    let i18n_manager = Arc::new(Mutex::new(I18nManager::new(app_config.general.language)));
-    let app_name = &app_config.app_name;
-    let app_path = std::env::current_exe().unwrap().display().to_string();
-    let auto_launch = AutoLaunch::new(app_name, &app_path, true, &[] as &[&str]);
+    if let Err(e) = run(app_config, logger, ipc_listener, metric_source) {
+        report_startup_error(&e, |i18n| i18n.get_message("startup-error-message"));
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+fn run(
+    app_config: AppConfig,
+    logger: Logger,
+    ipc_listener: Option<IpcListener>,
+    metric_source: MetricSourceEnum,
+) -> Result<()> {
+    let i18n_manager = Arc::new(Mutex::new(I18nManager::new(&app_config.general.language)?));
+    let system_monitor = SystemMonitor::open(&metric_source)?;
+    let app_name = app_config.app_name.clone();
+    let app_path = std::env::current_exe()
+        .map_err(AppError::CurrentExe)?
+        .display()
+        .to_string();
+    let auto_launch = AutoLaunchBuilder::new()
+        .set_app_name(&app_name)
+        .set_app_path(&app_path)
+        .set_use_launch_agent(true)
+        .build()?;
 
     let (width, height) = (
         app_config.window.settings_width,
         app_config.window.settings_height,
     );
-    // let window_title = {
-    //     let i18n_guard = i18n_manager.lock().unwrap();
-    //     i18n_guard.get_message("name")
-    // };
 
     let native_options = NativeOptions {
         viewport: egui::ViewportBuilder::default()
-            .with_title(app_name.to_string())
+            .with_title(app_name.clone())
             .with_inner_size([width, height])
             .with_resizable(true)
             .with_visible(true)
@@ -52,12 +200,87 @@ fn main() -> std::result::Result<(), eframe::Error> {
     };
 
     eframe::run_native(
-        app_name,
+        &app_name,
         native_options,
-        Box::new(|cc| {
-            let app = App::new(cc, app_config.clone(), i18n_manager, auto_launch)
-                .expect("Failed to create App");
+        Box::new(move |cc| {
+            let app = App::new(
+                cc,
+                app_config,
+                i18n_manager,
+                auto_launch,
+                logger,
+                ipc_listener,
+                system_monitor,
+            )
+            .map_err(|e| e.to_string())?;
             Ok(Box::new(app))
         }),
-    )
+    )?;
+    Ok(())
+}
+
+/// The GUI subsystem starts without a console, which would swallow the output
+/// of subcommands. They print to the console of the calling shell instead,
+/// unless the output is redirected.
+#[cfg(windows)]
+fn attach_parent_console() {
+    use windows_sys::Win32::System::Console::{
+        ATTACH_PARENT_PROCESS, AttachConsole, GetStdHandle, STD_OUTPUT_HANDLE,
+    };
+
+    // SAFETY: both calls have no preconditions. Attaching fails harmlessly
+    // when the parent has no console.
+    unsafe {
+        if GetStdHandle(STD_OUTPUT_HANDLE).is_null() {
+            AttachConsole(ATTACH_PARENT_PROCESS);
+        }
+    }
+}
+
+/// Brings up the settings window of the instance that is already running.
+/// An instance that has just started may not be listening yet, so connecting
+/// is retried for a moment.
+fn activate_running_instance() -> ExitCode {
+    const STARTUP_GRACE: Duration = Duration::from_secs(2);
+
+    let started = Instant::now();
+    let result = loop {
+        match ipc::ipc_client::send("show-settings") {
+            Err(e)
+                if matches!(
+                    e.kind(),
+                    io::ErrorKind::NotFound | io::ErrorKind::ConnectionRefused
+                ) && started.elapsed() < STARTUP_GRACE =>
+            {
+                std::thread::sleep(Duration::from_millis(100));
+            }
+            result => break result,
+        }
+    };
+    match result {
+        Ok(response) if response == "ok" => {
+            tracing::info!("Already running, showing the settings window of that instance");
+            ExitCode::SUCCESS
+        }
+        Ok(response) => {
+            tracing::error!(response, "Running instance rejected the activation request");
+            ExitCode::FAILURE
+        }
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to reach the running instance");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Logs a fatal error and shows it in a dialog. The dialog uses the system
+/// language, since the configured one may be what failed to load.
+fn report_startup_error(error: &AppError, summary: impl FnOnce(&I18nManager) -> String) {
+    tracing::error!(%error, "Failed to start");
+    match I18nManager::new(&LanguageSelectionEnum::SystemDefault) {
+        Ok(i18n) => {
+            ui::error_dialog::show_startup_error(&i18n, &summary(&i18n), &error.to_string())
+        }
+        Err(e) => tracing::error!(error = %e, "Failed to load translations for the error dialog"),
+    }
 }
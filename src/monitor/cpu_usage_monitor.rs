@@ -1,18 +1,22 @@
-use crate::{enums::monitor_type_enum::MonitorTypeEnum, monitor::monitor::Monitor};
-use sysinfo::System;
+use crate::{
+    enums::monitor_type_enum::MonitorTypeEnum,
+    monitor::{monitor::Monitor, system_source::SystemSource},
+};
 
 pub struct CpuUsageMonitor {
     value: f32,
 }
 
-impl Monitor for CpuUsageMonitor {
-    fn new() -> Self {
+impl CpuUsageMonitor {
+    pub fn new() -> Self {
         Self { value: 0.0 }
     }
+}
 
-    fn update(&mut self, sys: &mut System) {
-        sys.refresh_cpu_all();
-        self.value = sys.global_cpu_usage();
+impl Monitor for CpuUsageMonitor {
+    fn update(&mut self, source: &mut dyn SystemSource) {
+        source.refresh_cpu();
+        self.value = source.global_cpu_usage();
     }
 
     fn get_value(&self) -> f32 {
@@ -0,0 +1,212 @@
+use crate::{
+    enums::{monitor_type_enum::MonitorTypeEnum, monitor_unit_enum::MonitorUnitEnum},
+    monitor::monitor::MonitorSample,
+};
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HistoryPoint {
+    pub at: Instant,
+    pub value: f32,
+}
+
+#[derive(Debug, Clone)]
+pub struct MetricSeries {
+    pub unit: MonitorUnitEnum,
+    pub points: VecDeque<HistoryPoint>,
+}
+
+impl MetricSeries {
+    pub fn points_since(
+        &self,
+        now: Instant,
+        window: Duration,
+    ) -> impl Iterator<Item = &HistoryPoint> {
+        self.points
+            .iter()
+            .filter(move |point| now.saturating_duration_since(point.at) <= window)
+    }
+
+    /// Min/avg/max of the points recorded within `window`, or `None` when empty.
+    pub fn statistics(&self, now: Instant, window: Duration) -> Option<MetricStatistics> {
+        let mut count = 0usize;
+        let mut sum = 0.0f64;
+        let mut min = f32::INFINITY;
+        let mut max = f32::NEG_INFINITY;
+
+        for point in self.points_since(now, window) {
+            count += 1;
+            sum += point.value as f64;
+            min = min.min(point.value);
+            max = max.max(point.value);
+        }
+
+        (count > 0).then(|| MetricStatistics {
+            min,
+            avg: (sum / count as f64) as f32,
+            max,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MetricStatistics {
+    pub min: f32,
+    pub avg: f32,
+    pub max: f32,
+}
+
+/// Rolling per-monitor sample history used by charts and statistics.
+pub struct MetricHistory {
+    series: HashMap<MonitorTypeEnum, MetricSeries>,
+    retention: Duration,
+}
+
+impl MetricHistory {
+    pub const DEFAULT_RETENTION: Duration = Duration::from_secs(60 * 60);
+
+    pub fn new(retention: Duration) -> Self {
+        Self {
+            series: HashMap::new(),
+            retention,
+        }
+    }
+
+    pub fn record(&mut self, samples: &[MonitorSample], at: Instant) {
+        for sample in samples {
+            let series = self
+                .series
+                .entry(sample.monitor_type)
+                .or_insert_with(|| MetricSeries {
+                    unit: sample.unit,
+                    points: VecDeque::new(),
+                });
+
+            if series.unit != sample.unit {
+                series.unit = sample.unit;
+                series.points.clear();
+            }
+
+            series.points.push_back(HistoryPoint {
+                at,
+                value: sample.value,
+            });
+            while series
+                .points
+                .front()
+                .is_some_and(|point| at.saturating_duration_since(point.at) > self.retention)
+            {
+                series.points.pop_front();
+            }
+        }
+    }
+
+    pub fn series(&self, monitor_type: MonitorTypeEnum) -> Option<&MetricSeries> {
+        self.series.get(&monitor_type)
+    }
+
+    pub fn retain(&mut self, mut keep: impl FnMut(MonitorTypeEnum) -> bool) {
+        self.series.retain(|monitor_type, _| keep(*monitor_type));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enums::monitor_details_enum::MonitorDetailsEnum;
+
+    fn sample(monitor_type: MonitorTypeEnum, value: f32, unit: MonitorUnitEnum) -> MonitorSample {
+        MonitorSample {
+            monitor_type,
+            value,
+            unit,
+            details: MonitorDetailsEnum::None,
+        }
+    }
+
+    fn cpu(value: f32) -> MonitorSample {
+        sample(MonitorTypeEnum::CpuUsage, value, MonitorUnitEnum::Percent)
+    }
+
+    fn values(history: &MetricHistory, monitor_type: MonitorTypeEnum) -> Vec<f32> {
+        history
+            .series(monitor_type)
+            .unwrap()
+            .points
+            .iter()
+            .map(|point| point.value)
+            .collect()
+    }
+
+    #[test]
+    fn statistics_cover_the_window() {
+        let start = Instant::now();
+        let mut history = MetricHistory::new(MetricHistory::DEFAULT_RETENTION);
+        for (seconds, value) in [(0, 90.0), (10, 10.0), (20, 20.0), (30, 60.0)] {
+            history.record(&[cpu(value)], start + Duration::from_secs(seconds));
+        }
+        let series = history.series(MonitorTypeEnum::CpuUsage).unwrap();
+        let now = start + Duration::from_secs(30);
+
+        assert_eq!(
+            series.statistics(now, Duration::from_secs(60)),
+            Some(MetricStatistics {
+                min: 10.0,
+                avg: 45.0,
+                max: 90.0,
+            })
+        );
+        // The first point is older than the window.
+        assert_eq!(
+            series.statistics(now, Duration::from_secs(20)),
+            Some(MetricStatistics {
+                min: 10.0,
+                avg: 30.0,
+                max: 60.0,
+            })
+        );
+    }
+
+    #[test]
+    fn statistics_of_an_empty_window_are_none() {
+        let start = Instant::now();
+        let mut history = MetricHistory::new(MetricHistory::DEFAULT_RETENTION);
+        history.record(&[cpu(50.0)], start);
+        let series = history.series(MonitorTypeEnum::CpuUsage).unwrap();
+
+        let later = start + Duration::from_secs(120);
+        assert_eq!(series.statistics(later, Duration::from_secs(60)), None);
+    }
+
+    #[test]
+    fn record_trims_points_older_than_the_retention() {
+        let start = Instant::now();
+        let mut history = MetricHistory::new(Duration::from_secs(60));
+        for (seconds, value) in [(0, 1.0), (30, 2.0), (60, 3.0), (61, 4.0), (120, 5.0)] {
+            history.record(&[cpu(value)], start + Duration::from_secs(seconds));
+        }
+
+        // Points exactly at the retention limit are kept.
+        assert_eq!(values(&history, MonitorTypeEnum::CpuUsage), [3.0, 4.0, 5.0]);
+    }
+
+    #[test]
+    fn record_keeps_series_apart_and_restarts_on_unit_change() {
+        let start = Instant::now();
+        let group = MonitorTypeEnum::ProcessGroup(1);
+        let mut history = MetricHistory::new(MetricHistory::DEFAULT_RETENTION);
+        history.record(
+            &[cpu(10.0), sample(group, 5.0, MonitorUnitEnum::Percent)],
+            start,
+        );
+        history.record(
+            &[cpu(20.0), sample(group, 2048.0, MonitorUnitEnum::Bytes)],
+            start + Duration::from_secs(1),
+        );
+
+        assert_eq!(values(&history, MonitorTypeEnum::CpuUsage), [10.0, 20.0]);
+        assert_eq!(values(&history, group), [2048.0]);
+        assert_eq!(history.series(group).unwrap().unit, MonitorUnitEnum::Bytes);
+    }
+}
@@ -1,3 +1,13 @@
 pub mod cpu_usage_monitor;
+pub mod metric_history;
 pub mod monitor;
+pub mod pinned_process_monitor;
+pub mod process_filter;
+pub mod process_group_monitor;
 pub mod ram_usage_monitor;
+pub mod recording_system_source;
+pub mod replay_system_source;
+#[cfg(test)]
+pub mod scripted_system_source;
+pub mod system_snapshot;
+pub mod system_source;
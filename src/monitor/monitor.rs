@@ -1,48 +1,137 @@
 use crate::{
     config::app_config::AppConfig,
-    enums::monitor_type_enum::MonitorTypeEnum,
-    monitor::{cpu_usage_monitor, ram_usage_monitor},
+    enums::{
+        metric_source_enum::MetricSourceEnum, monitor_details_enum::MonitorDetailsEnum,
+        monitor_type_enum::MonitorTypeEnum, monitor_unit_enum::MonitorUnitEnum,
+    },
+    error::app_error::Result,
+    monitor::{
+        cpu_usage_monitor, pinned_process_monitor, process_group_monitor, ram_usage_monitor,
+        recording_system_source::RecordingSystemSource,
+        replay_system_source::ReplaySystemSource,
+        system_source::{SysinfoSource, SystemSource},
+    },
 };
-use sysinfo::System;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MonitorSample {
+    pub monitor_type: MonitorTypeEnum,
+    pub value: f32,
+    pub unit: MonitorUnitEnum,
+    pub details: MonitorDetailsEnum,
+}
 
 pub trait Monitor {
-    fn new() -> Self
-    where
-        Self: Sized;
-    fn update(&mut self, sys: &mut System);
+    /// Called before every update so monitors backed by config entries can pick up changes.
+    fn configure(&mut self, _app_config: &AppConfig) {}
+    /// Process-based monitors read the process table, which `SystemMonitor` refreshes
+    /// once per update so CPU usage is measured over the whole refresh interval.
+    fn needs_processes(&self) -> bool {
+        false
+    }
+    fn update(&mut self, source: &mut dyn SystemSource);
     fn get_value(&self) -> f32;
     fn get_type(&self) -> MonitorTypeEnum;
+
+    fn get_unit(&self) -> MonitorUnitEnum {
+        MonitorUnitEnum::Percent
+    }
+
+    fn get_details(&self) -> MonitorDetailsEnum {
+        MonitorDetailsEnum::None
+    }
+
+    fn get_sample(&self) -> MonitorSample {
+        MonitorSample {
+            monitor_type: self.get_type(),
+            value: self.get_value(),
+            unit: self.get_unit(),
+            details: self.get_details(),
+        }
+    }
 }
 
 pub trait MonitorManager {
-    fn update_all(&mut self, app_config: &AppConfig) -> Vec<(MonitorTypeEnum, f32)>;
+    fn update_all(&mut self, app_config: &AppConfig) -> Vec<MonitorSample>;
 }
 
 pub struct SystemMonitor {
-    sys: System,
+    source: Box<dyn SystemSource + Send>,
     monitors: Vec<Box<dyn Monitor + Send>>,
 }
 
 impl SystemMonitor {
     pub fn new() -> Self {
+        Self::with_source(Box::new(SysinfoSource::new()))
+    }
+
+    pub fn open(metric_source: &MetricSourceEnum) -> Result<Self> {
+        Ok(match metric_source {
+            MetricSourceEnum::Live => Self::new(),
+            MetricSourceEnum::Record(path) => Self::with_source(Box::new(
+                RecordingSystemSource::create(Box::new(SysinfoSource::new()), path)?,
+            )),
+            MetricSourceEnum::Replay { path, speed } => {
+                Self::with_source(Box::new(ReplaySystemSource::open(path, *speed)?))
+            }
+        })
+    }
+
+    pub fn with_source(source: Box<dyn SystemSource + Send>) -> Self {
         Self {
-            sys: System::new_all(),
-            monitors: vec![
-                Box::new(cpu_usage_monitor::CpuUsageMonitor::new()),
-                Box::new(ram_usage_monitor::RamUsageMonitor::new()),
-            ],
+            source,
+            monitors: MonitorTypeEnum::builtin()
+                .map(Self::create_monitor)
+                .collect(),
+        }
+    }
+
+    fn create_monitor(monitor_type: MonitorTypeEnum) -> Box<dyn Monitor + Send> {
+        match monitor_type {
+            MonitorTypeEnum::CpuUsage => Box::new(cpu_usage_monitor::CpuUsageMonitor::new()),
+            MonitorTypeEnum::RamUsage => Box::new(ram_usage_monitor::RamUsageMonitor::new()),
+            MonitorTypeEnum::PinnedProcess(id) => {
+                Box::new(pinned_process_monitor::PinnedProcessMonitor::new(id))
+            }
+            MonitorTypeEnum::ProcessGroup(id) => {
+                Box::new(process_group_monitor::ProcessGroupMonitor::new(id))
+            }
+        }
+    }
+
+    /// Adds and removes monitors so they match the entries in `app_config`.
+    fn sync_monitors(&mut self, app_config: &AppConfig) {
+        let monitor_types = app_config.monitor_types();
+        self.monitors
+            .retain(|m| monitor_types.contains(&m.get_type()));
+
+        for monitor_type in monitor_types {
+            if !self.monitors.iter().any(|m| m.get_type() == monitor_type) {
+                self.monitors.push(Self::create_monitor(monitor_type));
+            }
         }
     }
 }
 
 impl MonitorManager for SystemMonitor {
-    fn update_all(&mut self, app_config: &AppConfig) -> Vec<(MonitorTypeEnum, f32)> {
+    fn update_all(&mut self, app_config: &AppConfig) -> Vec<MonitorSample> {
+        self.sync_monitors(app_config);
+
+        let source = self.source.as_mut();
+        source.begin_update();
+        let mut processes_refreshed = false;
+
         self.monitors
             .iter_mut()
             .filter_map(|m| {
-                if app_config.active_monitors.contains(&m.get_type()) {
-                    m.update(&mut self.sys);
-                    Some((m.get_type(), m.get_value()))
+                if app_config.is_monitor_active(m.get_type()) {
+                    m.configure(app_config);
+                    if m.needs_processes() && !processes_refreshed {
+                        source.refresh_processes();
+                        processes_refreshed = true;
+                    }
+                    m.update(source);
+                    Some(m.get_sample())
                 } else {
                     None
                 }
@@ -50,3 +139,150 @@ impl MonitorManager for SystemMonitor {
             .collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::app_config::ProcessGroupConfig;
+    use crate::enums::process_filter_enum::ProcessFilterEnum;
+    use crate::enums::process_metric_enum::ProcessMetricEnum;
+    use crate::monitor::scripted_system_source::{ScriptedSystemSource, process};
+    use crate::monitor::system_snapshot::SystemSnapshot;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn group(id: u32, pattern: &str, enabled: bool) -> ProcessGroupConfig {
+        ProcessGroupConfig {
+            id,
+            label: String::new(),
+            filter: ProcessFilterEnum::NamePattern(pattern.to_string()),
+            metric: ProcessMetricEnum::CpuUsage,
+            enabled,
+        }
+    }
+
+    fn snapshot(cpu_usage: f32) -> SystemSnapshot {
+        SystemSnapshot {
+            cpu_usage,
+            used_memory: 2,
+            total_memory: 8,
+            processes: vec![
+                process(1, "firefox", 10.0, 100),
+                process(2, "code", 5.0, 50),
+            ],
+        }
+    }
+
+    fn scripted_monitor(
+        frames: impl IntoIterator<Item = SystemSnapshot>,
+    ) -> (SystemMonitor, Arc<AtomicUsize>) {
+        let source = ScriptedSystemSource::new(frames);
+        let process_refreshes = source.process_refreshes.clone();
+        (
+            SystemMonitor::with_source(Box::new(source)),
+            process_refreshes,
+        )
+    }
+
+    fn monitor_types(samples: &[MonitorSample]) -> Vec<MonitorTypeEnum> {
+        samples.iter().map(|sample| sample.monitor_type).collect()
+    }
+
+    #[test]
+    fn update_all_samples_only_active_monitors() {
+        let (mut monitor, _) = scripted_monitor([snapshot(30.0)]);
+        let mut app_config = AppConfig::default();
+        app_config.set_monitor_active(MonitorTypeEnum::RamUsage, false);
+
+        let samples = monitor.update_all(&app_config);
+
+        assert_eq!(monitor_types(&samples), [MonitorTypeEnum::CpuUsage]);
+        assert_eq!(samples[0].value, 30.0);
+    }
+
+    #[test]
+    fn update_all_skips_disabled_process_groups() {
+        let (mut monitor, _) = scripted_monitor([snapshot(0.0)]);
+        let app_config = AppConfig {
+            process_groups: vec![group(1, "firefox", false), group(2, "code", true)],
+            ..AppConfig::default()
+        };
+
+        let samples = monitor.update_all(&app_config);
+
+        assert_eq!(
+            monitor_types(&samples),
+            [
+                MonitorTypeEnum::CpuUsage,
+                MonitorTypeEnum::RamUsage,
+                MonitorTypeEnum::ProcessGroup(2),
+            ]
+        );
+        assert_eq!(samples[2].value, 5.0);
+    }
+
+    #[test]
+    fn update_all_refreshes_processes_once_per_cycle() {
+        let mut app_config = AppConfig {
+            process_groups: vec![group(1, "firefox", true), group(2, "code", true)],
+            ..AppConfig::default()
+        };
+        let (mut monitor, process_refreshes) = scripted_monitor([snapshot(0.0)]);
+
+        monitor.update_all(&app_config);
+        assert_eq!(process_refreshes.load(Ordering::Relaxed), 1);
+
+        app_config
+            .process_groups
+            .iter_mut()
+            .for_each(|group| group.enabled = false);
+        monitor.update_all(&app_config);
+        assert_eq!(process_refreshes.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn update_all_drops_monitors_removed_from_config() {
+        let mut app_config = AppConfig {
+            process_groups: vec![group(1, "firefox", true)],
+            ..AppConfig::default()
+        };
+        let (mut monitor, _) = scripted_monitor([snapshot(0.0)]);
+        assert_eq!(monitor.update_all(&app_config).len(), 3);
+
+        app_config.process_groups.clear();
+        let samples = monitor.update_all(&app_config);
+
+        assert_eq!(
+            monitor_types(&samples),
+            [MonitorTypeEnum::CpuUsage, MonitorTypeEnum::RamUsage]
+        );
+    }
+
+    #[test]
+    fn removing_an_entry_keeps_the_ids_of_the_others() {
+        let mut app_config = AppConfig {
+            process_groups: vec![group(1, "firefox", true), group(2, "code", true)],
+            ..AppConfig::default()
+        };
+        let (mut monitor, _) = scripted_monitor([snapshot(0.0)]);
+        monitor.update_all(&app_config);
+
+        app_config.remove_monitor(MonitorTypeEnum::ProcessGroup(1));
+        let samples = monitor.update_all(&app_config);
+
+        assert_eq!(samples[2].monitor_type, MonitorTypeEnum::ProcessGroup(2));
+        assert_eq!(samples[2].value, 5.0);
+    }
+
+    #[test]
+    fn update_all_advances_through_script() {
+        let (mut monitor, _) = scripted_monitor([snapshot(10.0), snapshot(20.0)]);
+        let app_config = AppConfig::default();
+
+        let values: Vec<f32> = (0..3)
+            .map(|_| monitor.update_all(&app_config)[0].value)
+            .collect();
+
+        assert_eq!(values, [10.0, 20.0, 20.0]);
+    }
+}
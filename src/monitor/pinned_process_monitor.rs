@@ -0,0 +1,201 @@
+use crate::{
+    config::app_config::{AppConfig, PinnedProcessConfig},
+    enums::process_filter_enum::ProcessFilterEnum,
+    enums::{
+        monitor_type_enum::MonitorTypeEnum, monitor_unit_enum::MonitorUnitEnum,
+        process_match_rule_enum::ProcessMatchRuleEnum, process_metric_enum::ProcessMetricEnum,
+    },
+    monitor::{
+        monitor::Monitor,
+        process_filter::ProcessFilter,
+        system_source::{ProcessInfo, SystemSource},
+    },
+};
+
+/// Follows a single process selected by a `ProcessMatchRuleEnum`. When the tracked
+/// process exits, the rule is matched again so a restarted process is picked up.
+pub struct PinnedProcessMonitor {
+    id: u32,
+    config: Option<PinnedProcessConfig>,
+    name_filter: Option<ProcessFilter>,
+    pid: Option<u32>,
+    value: f32,
+}
+
+impl PinnedProcessMonitor {
+    pub fn new(id: u32) -> Self {
+        Self {
+            id,
+            config: None,
+            name_filter: None,
+            pid: None,
+            value: 0.0,
+        }
+    }
+
+    fn matches(&self, process: &ProcessInfo) -> bool {
+        match self.config.as_ref().map(|config| &config.rule) {
+            Some(ProcessMatchRuleEnum::Pid(pid)) => process.pid == *pid,
+            Some(ProcessMatchRuleEnum::NamePattern(_)) => self
+                .name_filter
+                .as_ref()
+                .is_some_and(|filter| filter.matches(process)),
+            None => false,
+        }
+    }
+
+    /// Picks the longest-running matching process, which is usually the parent
+    /// when a program spawns several processes with the same name.
+    fn resolve_pid(&self, source: &dyn SystemSource) -> Option<u32> {
+        source
+            .processes()
+            .iter()
+            .filter(|process| self.matches(process))
+            .min_by_key(|process| (process.start_time, process.pid))
+            .map(|process| process.pid)
+    }
+
+    fn metric(&self) -> ProcessMetricEnum {
+        self.config
+            .as_ref()
+            .map(|config| config.metric)
+            .unwrap_or_default()
+    }
+}
+
+impl Monitor for PinnedProcessMonitor {
+    fn configure(&mut self, app_config: &AppConfig) {
+        let config = app_config.pinned_process(self.id).cloned();
+        let rule_changed =
+            config.as_ref().map(|c| &c.rule) != self.config.as_ref().map(|c| &c.rule);
+
+        if rule_changed {
+            self.pid = None;
+            self.name_filter = match config.as_ref().map(|c| &c.rule) {
+                Some(ProcessMatchRuleEnum::NamePattern(pattern)) => {
+                    ProcessFilter::compile(&ProcessFilterEnum::NamePattern(pattern.clone()))
+                }
+                _ => None,
+            };
+        }
+
+        self.config = config;
+    }
+
+    fn needs_processes(&self) -> bool {
+        true
+    }
+
+    fn update(&mut self, source: &mut dyn SystemSource) {
+        if self.pid.is_some_and(|pid| {
+            !source
+                .process(pid)
+                .is_some_and(|process| self.matches(process))
+        }) {
+            self.pid = None;
+        }
+
+        if self.pid.is_none() {
+            self.pid = self.resolve_pid(source);
+        }
+
+        let metric = self.metric();
+        self.value =
+            self.pid
+                .and_then(|pid| source.process(pid))
+                .map_or(0.0, |process| match metric {
+                    ProcessMetricEnum::CpuUsage => process.cpu_usage,
+                    ProcessMetricEnum::Memory => process.memory as f32,
+                });
+    }
+
+    fn get_value(&self) -> f32 {
+        self.value
+    }
+
+    fn get_type(&self) -> MonitorTypeEnum {
+        MonitorTypeEnum::PinnedProcess(self.id)
+    }
+
+    fn get_unit(&self) -> MonitorUnitEnum {
+        self.metric().unit()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monitor::scripted_system_source::{ScriptedSystemSource, process};
+    use crate::monitor::system_snapshot::SystemSnapshot;
+
+    fn pinned_monitor(rule: ProcessMatchRuleEnum) -> PinnedProcessMonitor {
+        let app_config = AppConfig {
+            pinned_processes: vec![PinnedProcessConfig {
+                id: 1,
+                label: String::new(),
+                rule,
+                metric: ProcessMetricEnum::CpuUsage,
+                enabled: true,
+            }],
+            ..AppConfig::default()
+        };
+        let mut monitor = PinnedProcessMonitor::new(1);
+        monitor.configure(&app_config);
+        monitor
+    }
+
+    fn frame(processes: Vec<ProcessInfo>) -> SystemSnapshot {
+        SystemSnapshot {
+            processes,
+            ..SystemSnapshot::default()
+        }
+    }
+
+    #[test]
+    fn follows_the_oldest_matching_process() {
+        let mut monitor = pinned_monitor(ProcessMatchRuleEnum::NamePattern("^code$".to_string()));
+        let mut source = ScriptedSystemSource::new([frame(vec![
+            process(7, "code", 3.0, 0),
+            process(4, "code", 9.0, 0),
+        ])]);
+        source.begin_update();
+
+        monitor.update(&mut source);
+
+        assert_eq!(monitor.get_value(), 9.0);
+    }
+
+    #[test]
+    fn picks_up_a_restarted_process() {
+        let mut monitor = pinned_monitor(ProcessMatchRuleEnum::NamePattern("^code$".to_string()));
+        let mut source = ScriptedSystemSource::new([
+            frame(vec![process(4, "code", 9.0, 0)]),
+            frame(vec![]),
+            frame(vec![process(12, "code", 2.0, 0)]),
+        ]);
+
+        let values: Vec<f32> = (0..3)
+            .map(|_| {
+                source.begin_update();
+                monitor.update(&mut source);
+                monitor.get_value()
+            })
+            .collect();
+
+        assert_eq!(values, [9.0, 0.0, 2.0]);
+    }
+
+    #[test]
+    fn pid_rule_ignores_other_processes() {
+        let mut monitor = pinned_monitor(ProcessMatchRuleEnum::Pid(2));
+        let mut source = ScriptedSystemSource::new([frame(vec![
+            process(1, "init", 1.0, 0),
+            process(2, "sshd", 4.0, 0),
+        ])]);
+        source.begin_update();
+
+        monitor.update(&mut source);
+
+        assert_eq!(monitor.get_value(), 4.0);
+    }
+}
@@ -0,0 +1,82 @@
+use crate::enums::process_filter_enum::ProcessFilterEnum;
+use crate::monitor::system_source::ProcessInfo;
+use regex::Regex;
+
+/// A compiled `ProcessFilterEnum`.
+pub struct ProcessFilter {
+    regex: Regex,
+    match_command_line: bool,
+}
+
+impl ProcessFilter {
+    pub fn new(filter: &ProcessFilterEnum) -> Result<Self, regex::Error> {
+        Ok(Self {
+            regex: Regex::new(filter.pattern())?,
+            match_command_line: matches!(filter, ProcessFilterEnum::CommandLinePattern(_)),
+        })
+    }
+
+    /// Compiles the filter, logging and discarding invalid patterns.
+    pub fn compile(filter: &ProcessFilterEnum) -> Option<Self> {
+        Self::new(filter)
+            .inspect_err(|e| {
+                tracing::warn!(pattern = filter.pattern(), error = %e, "Invalid process pattern")
+            })
+            .ok()
+    }
+
+    pub fn matches(&self, process: &ProcessInfo) -> bool {
+        if process.is_thread {
+            return false;
+        }
+
+        if self.match_command_line {
+            self.regex.is_match(&process.command_line)
+        } else {
+            self.regex.is_match(&process.name)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monitor::scripted_system_source::process;
+
+    fn node_process() -> ProcessInfo {
+        let mut node = process(1, "node", 0.0, 0);
+        node.command_line = "/usr/bin/node /srv/app/server.js --port 80".to_string();
+        node
+    }
+
+    #[test]
+    fn name_pattern_ignores_command_line() {
+        let filter = ProcessFilter::new(&ProcessFilterEnum::NamePattern("server".into())).unwrap();
+        assert!(!filter.matches(&node_process()));
+
+        let filter = ProcessFilter::new(&ProcessFilterEnum::NamePattern("^no".into())).unwrap();
+        assert!(filter.matches(&node_process()));
+    }
+
+    #[test]
+    fn command_line_pattern_matches_arguments() {
+        let filter =
+            ProcessFilter::new(&ProcessFilterEnum::CommandLinePattern("server\\.js".into()))
+                .unwrap();
+        assert!(filter.matches(&node_process()));
+    }
+
+    #[test]
+    fn threads_never_match() {
+        let mut thread = node_process();
+        thread.is_thread = true;
+
+        let filter = ProcessFilter::new(&ProcessFilterEnum::NamePattern("node".into())).unwrap();
+        assert!(!filter.matches(&thread));
+    }
+
+    #[test]
+    fn invalid_pattern_is_rejected() {
+        assert!(ProcessFilter::compile(&ProcessFilterEnum::NamePattern("(".into())).is_none());
+    }
+}
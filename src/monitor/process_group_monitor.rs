@@ -0,0 +1,197 @@
+use crate::{
+    config::app_config::{AppConfig, ProcessGroupConfig},
+    enums::{
+        monitor_details_enum::MonitorDetailsEnum, monitor_type_enum::MonitorTypeEnum,
+        monitor_unit_enum::MonitorUnitEnum, process_metric_enum::ProcessMetricEnum,
+    },
+    monitor::{monitor::Monitor, process_filter::ProcessFilter, system_source::SystemSource},
+};
+
+/// Sums CPU and memory usage over every process matching a `ProcessFilterEnum`.
+pub struct ProcessGroupMonitor {
+    id: u32,
+    config: Option<ProcessGroupConfig>,
+    filter: Option<ProcessFilter>,
+    cpu_usage: f32,
+    memory: u64,
+    process_count: usize,
+}
+
+impl ProcessGroupMonitor {
+    pub fn new(id: u32) -> Self {
+        Self {
+            id,
+            config: None,
+            filter: None,
+            cpu_usage: 0.0,
+            memory: 0,
+            process_count: 0,
+        }
+    }
+
+    fn metric(&self) -> ProcessMetricEnum {
+        self.config
+            .as_ref()
+            .map(|config| config.metric)
+            .unwrap_or_default()
+    }
+}
+
+impl Monitor for ProcessGroupMonitor {
+    fn configure(&mut self, app_config: &AppConfig) {
+        let config = app_config.process_group(self.id).cloned();
+        let filter_changed =
+            config.as_ref().map(|c| &c.filter) != self.config.as_ref().map(|c| &c.filter);
+
+        if filter_changed {
+            self.filter = config
+                .as_ref()
+                .and_then(|c| ProcessFilter::compile(&c.filter));
+        }
+
+        self.config = config;
+    }
+
+    fn needs_processes(&self) -> bool {
+        true
+    }
+
+    fn update(&mut self, source: &mut dyn SystemSource) {
+        self.cpu_usage = 0.0;
+        self.memory = 0;
+        self.process_count = 0;
+
+        let Some(filter) = &self.filter else {
+            return;
+        };
+
+        for process in source.processes().iter().filter(|p| filter.matches(p)) {
+            self.cpu_usage += process.cpu_usage;
+            self.memory += process.memory;
+            self.process_count += 1;
+        }
+    }
+
+    fn get_value(&self) -> f32 {
+        match self.metric() {
+            ProcessMetricEnum::CpuUsage => self.cpu_usage,
+            ProcessMetricEnum::Memory => self.memory as f32,
+        }
+    }
+
+    fn get_type(&self) -> MonitorTypeEnum {
+        MonitorTypeEnum::ProcessGroup(self.id)
+    }
+
+    fn get_unit(&self) -> MonitorUnitEnum {
+        self.metric().unit()
+    }
+
+    fn get_details(&self) -> MonitorDetailsEnum {
+        MonitorDetailsEnum::ProcessGroup {
+            cpu_usage: self.cpu_usage,
+            memory: self.memory,
+            process_count: self.process_count,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enums::process_filter_enum::ProcessFilterEnum;
+    use crate::monitor::scripted_system_source::{ScriptedSystemSource, process};
+    use crate::monitor::system_snapshot::SystemSnapshot;
+    use crate::monitor::system_source::ProcessInfo;
+
+    fn update_group(
+        filter: ProcessFilterEnum,
+        metric: ProcessMetricEnum,
+        processes: Vec<ProcessInfo>,
+    ) -> ProcessGroupMonitor {
+        let app_config = AppConfig {
+            process_groups: vec![ProcessGroupConfig {
+                id: 1,
+                label: String::new(),
+                filter,
+                metric,
+                enabled: true,
+            }],
+            ..AppConfig::default()
+        };
+        let mut source = ScriptedSystemSource::new([SystemSnapshot {
+            processes,
+            ..SystemSnapshot::default()
+        }]);
+        source.begin_update();
+
+        let mut monitor = ProcessGroupMonitor::new(1);
+        monitor.configure(&app_config);
+        monitor.update(&mut source);
+        monitor
+    }
+
+    #[test]
+    fn sums_usage_of_matching_processes() {
+        let monitor = update_group(
+            ProcessFilterEnum::NamePattern("^chrom".to_string()),
+            ProcessMetricEnum::CpuUsage,
+            vec![
+                process(1, "chrome", 150.0, 300),
+                process(2, "chromium", 12.5, 200),
+                process(3, "firefox", 40.0, 500),
+            ],
+        );
+
+        // Usage is per core, so a group may exceed 100%.
+        assert_eq!(monitor.get_value(), 162.5);
+        assert_eq!(
+            monitor.get_details(),
+            MonitorDetailsEnum::ProcessGroup {
+                cpu_usage: 162.5,
+                memory: 500,
+                process_count: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn memory_metric_reports_bytes() {
+        let monitor = update_group(
+            ProcessFilterEnum::NamePattern("code".to_string()),
+            ProcessMetricEnum::Memory,
+            vec![process(1, "code", 1.0, 1024), process(2, "code", 1.0, 2048)],
+        );
+
+        assert_eq!(monitor.get_value(), 3072.0);
+        assert_eq!(monitor.get_unit(), MonitorUnitEnum::Bytes);
+    }
+
+    #[test]
+    fn no_matching_processes_reports_zero() {
+        let monitor = update_group(
+            ProcessFilterEnum::NamePattern("missing".to_string()),
+            ProcessMetricEnum::CpuUsage,
+            vec![process(1, "code", 30.0, 100)],
+        );
+
+        assert_eq!(monitor.get_value(), 0.0);
+    }
+
+    #[test]
+    fn skips_threads_and_matches_command_lines() {
+        let mut thread = process(2, "worker", 50.0, 0);
+        thread.command_line = "python server.py".to_string();
+        thread.is_thread = true;
+        let mut server = process(1, "python3", 20.0, 100);
+        server.command_line = "python server.py".to_string();
+
+        let monitor = update_group(
+            ProcessFilterEnum::CommandLinePattern("server\\.py".to_string()),
+            ProcessMetricEnum::CpuUsage,
+            vec![server, thread],
+        );
+
+        assert_eq!(monitor.get_value(), 20.0);
+    }
+}
@@ -1,18 +1,35 @@
-use crate::{enums::monitor_type_enum::MonitorTypeEnum, monitor::monitor::Monitor};
-use sysinfo::System;
+use crate::{
+    enums::{monitor_details_enum::MonitorDetailsEnum, monitor_type_enum::MonitorTypeEnum},
+    monitor::{monitor::Monitor, system_source::SystemSource},
+};
 
 pub struct RamUsageMonitor {
     value: f32,
+    used: u64,
+    total: u64,
 }
 
-impl Monitor for RamUsageMonitor {
-    fn new() -> Self {
-        Self { value: 0.0 }
+impl RamUsageMonitor {
+    pub fn new() -> Self {
+        Self {
+            value: 0.0,
+            used: 0,
+            total: 0,
+        }
     }
+}
 
-    fn update(&mut self, sys: &mut System) {
-        sys.refresh_memory();
-        self.value = (sys.used_memory() as f32 / sys.total_memory() as f32) * 100.0;
+impl Monitor for RamUsageMonitor {
+    fn update(&mut self, source: &mut dyn SystemSource) {
+        source.refresh_memory();
+        self.used = source.used_memory();
+        self.total = source.total_memory();
+        // Some sandboxes report no memory at all, which would make this NaN.
+        self.value = if self.total == 0 {
+            0.0
+        } else {
+            (self.used as f32 / self.total as f32) * 100.0
+        };
     }
 
     fn get_value(&self) -> f32 {
@@ -22,4 +39,54 @@ impl Monitor for RamUsageMonitor {
     fn get_type(&self) -> MonitorTypeEnum {
         MonitorTypeEnum::RamUsage
     }
+
+    fn get_details(&self) -> MonitorDetailsEnum {
+        MonitorDetailsEnum::Memory {
+            used: self.used,
+            total: self.total,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monitor::scripted_system_source::ScriptedSystemSource;
+    use crate::monitor::system_snapshot::SystemSnapshot;
+
+    fn sample_with(used_memory: u64, total_memory: u64) -> (f32, MonitorDetailsEnum) {
+        let mut source = ScriptedSystemSource::new([SystemSnapshot {
+            used_memory,
+            total_memory,
+            ..SystemSnapshot::default()
+        }]);
+        source.begin_update();
+        let mut monitor = RamUsageMonitor::new();
+        monitor.update(&mut source);
+        (monitor.get_value(), monitor.get_details())
+    }
+
+    #[test]
+    fn reports_used_memory_as_percentage() {
+        let (value, details) = sample_with(4 << 30, 16 << 30);
+
+        assert_eq!(value, 25.0);
+        assert_eq!(
+            details,
+            MonitorDetailsEnum::Memory {
+                used: 4 << 30,
+                total: 16 << 30,
+            }
+        );
+    }
+
+    #[test]
+    fn full_memory_is_one_hundred_percent() {
+        assert_eq!(sample_with(8 << 30, 8 << 30).0, 100.0);
+    }
+
+    #[test]
+    fn zero_total_memory_reports_zero_instead_of_nan() {
+        assert_eq!(sample_with(0, 0).0, 0.0);
+    }
 }
@@ -0,0 +1,131 @@
+use crate::error::app_error::{AppError, Result};
+use crate::monitor::system_snapshot::{RecordedFrame, SystemSnapshot};
+use crate::monitor::system_source::{ProcessInfo, SystemSource};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+/// Passes another source through while appending every update cycle to a
+/// recording that `ReplaySystemSource` can play back.
+pub struct RecordingSystemSource {
+    inner: Box<dyn SystemSource + Send>,
+    /// Dropped after a write error so recording stops instead of failing every cycle.
+    output: Option<BufWriter<File>>,
+    path: PathBuf,
+    started: Option<Instant>,
+}
+
+impl RecordingSystemSource {
+    pub fn create(inner: Box<dyn SystemSource + Send>, path: &Path) -> Result<Self> {
+        let file = File::create(path).map_err(|e| AppError::io(path, e))?;
+        tracing::info!(path = %path.display(), "Recording metrics");
+        Ok(Self {
+            inner,
+            output: Some(BufWriter::new(file)),
+            path: path.to_path_buf(),
+            started: None,
+        })
+    }
+}
+
+impl SystemSource for RecordingSystemSource {
+    /// Refreshes everything up front so the recording is complete whichever
+    /// monitors are active, which also makes the `refresh_*` calls no-ops.
+    fn begin_update(&mut self) {
+        self.inner.begin_update();
+        self.inner.refresh_cpu();
+        self.inner.refresh_memory();
+        self.inner.refresh_processes();
+
+        let Some(output) = self.output.as_mut() else {
+            return;
+        };
+        let started = *self.started.get_or_insert_with(Instant::now);
+        let frame = RecordedFrame {
+            elapsed_ms: started.elapsed().as_millis() as u64,
+            snapshot: SystemSnapshot::capture(self.inner.as_ref()),
+        };
+        // Flushed every cycle so a crash loses at most one frame.
+        if let Err(e) = frame.write(output).and_then(|()| output.flush()) {
+            tracing::error!(path = %self.path.display(), error = %e, "Failed to write recording, stopping");
+            self.output = None;
+        }
+    }
+
+    fn refresh_cpu(&mut self) {}
+
+    fn refresh_memory(&mut self) {}
+
+    fn refresh_processes(&mut self) {}
+
+    fn global_cpu_usage(&self) -> f32 {
+        self.inner.global_cpu_usage()
+    }
+
+    fn used_memory(&self) -> u64 {
+        self.inner.used_memory()
+    }
+
+    fn total_memory(&self) -> u64 {
+        self.inner.total_memory()
+    }
+
+    fn processes(&self) -> &[ProcessInfo] {
+        self.inner.processes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::app_config::AppConfig;
+    use crate::monitor::monitor::{MonitorManager, SystemMonitor};
+    use crate::monitor::scripted_system_source::{ScriptedSystemSource, process};
+    use std::io::BufReader;
+
+    #[test]
+    fn records_every_update_cycle() {
+        let snapshots = vec![
+            SystemSnapshot {
+                cpu_usage: 10.0,
+                used_memory: 1 << 30,
+                total_memory: 2 << 30,
+                processes: vec![process(1, "init", 0.0, 4096)],
+            },
+            SystemSnapshot {
+                cpu_usage: 90.0,
+                used_memory: 2 << 30,
+                total_memory: 2 << 30,
+                processes: vec![
+                    process(1, "init", 0.0, 4096),
+                    process(2, "make", 99.0, 1024),
+                ],
+            },
+        ];
+        let path = std::env::temp_dir().join(format!(
+            "rust_system_monitor_recording_{}.jsonl",
+            std::process::id()
+        ));
+        let source = RecordingSystemSource::create(
+            Box::new(ScriptedSystemSource::new(snapshots.clone())),
+            &path,
+        )
+        .unwrap();
+        let mut monitor = SystemMonitor::with_source(Box::new(source));
+
+        let cpu_usages: Vec<f32> = (0..2)
+            .map(|_| monitor.update_all(&AppConfig::default())[0].value)
+            .collect();
+        let frames = RecordedFrame::read_all(BufReader::new(File::open(&path).unwrap()), &path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(cpu_usages, [10.0, 90.0]);
+        let frames = frames.unwrap();
+        assert_eq!(
+            frames.iter().map(|f| &f.snapshot).collect::<Vec<_>>(),
+            snapshots.iter().collect::<Vec<_>>()
+        );
+        assert!(frames[0].elapsed_ms <= frames[1].elapsed_ms);
+    }
+}
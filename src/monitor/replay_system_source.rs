@@ -0,0 +1,136 @@
+use crate::error::app_error::{AppError, Result};
+use crate::monitor::system_snapshot::RecordedFrame;
+use crate::monitor::system_source::{ProcessInfo, SystemSource};
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Plays back a recording made by `RecordingSystemSource` in place of live
+/// data. Frames follow the recorded timing scaled by `speed`; the last frame
+/// stays current once the recording ends.
+pub struct ReplaySystemSource {
+    frames: Vec<RecordedFrame>,
+    speed: f64,
+    /// Set on the first update, so time spent starting up doesn't skip frames.
+    started: Option<Instant>,
+    current: usize,
+}
+
+impl ReplaySystemSource {
+    pub fn open(path: &Path, speed: f64) -> Result<Self> {
+        let file = File::open(path).map_err(|e| AppError::io(path, e))?;
+        let frames = RecordedFrame::read_all(BufReader::new(file), path)?;
+        if frames.is_empty() {
+            return Err(AppError::EmptyRecording(path.display().to_string()));
+        }
+        tracing::info!(path = %path.display(), frames = frames.len(), speed, "Replaying metrics");
+        Ok(Self::new(frames, speed))
+    }
+
+    fn new(frames: Vec<RecordedFrame>, speed: f64) -> Self {
+        Self {
+            frames,
+            speed,
+            started: None,
+            current: 0,
+        }
+    }
+
+    /// Index of the frame that is current `elapsed` into the replay.
+    fn frame_at(&self, elapsed: Duration) -> usize {
+        let position_ms = (elapsed.as_secs_f64() * self.speed * 1000.0) as u64;
+        self.frames
+            .partition_point(|frame| frame.elapsed_ms <= position_ms)
+            .saturating_sub(1)
+    }
+}
+
+impl SystemSource for ReplaySystemSource {
+    fn begin_update(&mut self) {
+        let started = *self.started.get_or_insert_with(Instant::now);
+        let next = self.frame_at(started.elapsed());
+        if next == self.frames.len() - 1 && self.current != next {
+            tracing::info!("Replay reached the end of the recording");
+        }
+        self.current = next;
+    }
+
+    fn refresh_cpu(&mut self) {}
+
+    fn refresh_memory(&mut self) {}
+
+    fn refresh_processes(&mut self) {}
+
+    fn global_cpu_usage(&self) -> f32 {
+        self.frames[self.current].snapshot.cpu_usage
+    }
+
+    fn used_memory(&self) -> u64 {
+        self.frames[self.current].snapshot.used_memory
+    }
+
+    fn total_memory(&self) -> u64 {
+        self.frames[self.current].snapshot.total_memory
+    }
+
+    fn processes(&self) -> &[ProcessInfo] {
+        &self.frames[self.current].snapshot.processes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::app_config::AppConfig;
+    use crate::monitor::monitor::{MonitorManager, SystemMonitor};
+    use crate::monitor::system_snapshot::SystemSnapshot;
+
+    fn frames(cpu_usages: &[(u64, f32)]) -> Vec<RecordedFrame> {
+        cpu_usages
+            .iter()
+            .map(|&(elapsed_ms, cpu_usage)| RecordedFrame {
+                elapsed_ms,
+                snapshot: SystemSnapshot {
+                    cpu_usage,
+                    ..SystemSnapshot::default()
+                },
+            })
+            .collect()
+    }
+
+    #[test]
+    fn follows_recorded_timing() {
+        let replay = ReplaySystemSource::new(frames(&[(0, 1.0), (1000, 2.0), (2000, 3.0)]), 1.0);
+
+        assert_eq!(replay.frame_at(Duration::ZERO), 0);
+        assert_eq!(replay.frame_at(Duration::from_millis(999)), 0);
+        assert_eq!(replay.frame_at(Duration::from_millis(1000)), 1);
+        assert_eq!(replay.frame_at(Duration::from_millis(2500)), 2);
+    }
+
+    #[test]
+    fn speed_scales_timing() {
+        let replay = ReplaySystemSource::new(frames(&[(0, 1.0), (1000, 2.0), (2000, 3.0)]), 4.0);
+
+        assert_eq!(replay.frame_at(Duration::from_millis(250)), 1);
+        assert_eq!(replay.frame_at(Duration::from_millis(500)), 2);
+    }
+
+    #[test]
+    fn holds_last_frame_after_the_end() {
+        let replay = ReplaySystemSource::new(frames(&[(0, 1.0), (1000, 2.0)]), 1.0);
+
+        assert_eq!(replay.frame_at(Duration::from_secs(3600)), 1);
+    }
+
+    #[test]
+    fn feeds_system_monitor() {
+        let replay = ReplaySystemSource::new(frames(&[(0, 42.0), (60_000, 7.0)]), 1.0);
+        let mut monitor = SystemMonitor::with_source(Box::new(replay));
+
+        let samples = monitor.update_all(&AppConfig::default());
+
+        assert_eq!(samples[0].value, 42.0);
+    }
+}
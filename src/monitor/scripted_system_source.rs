@@ -0,0 +1,69 @@
+use crate::monitor::system_snapshot::SystemSnapshot;
+use crate::monitor::system_source::{ProcessInfo, SystemSource};
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Test double that reports a fixed sequence of snapshots, moving to the next
+/// one on every update cycle. The last snapshot repeats once the script ends.
+pub struct ScriptedSystemSource {
+    frames: VecDeque<SystemSnapshot>,
+    current: SystemSnapshot,
+    /// Number of `refresh_processes` calls, shared so tests can read it after
+    /// handing the source to a `SystemMonitor`.
+    pub process_refreshes: Arc<AtomicUsize>,
+}
+
+impl ScriptedSystemSource {
+    pub fn new(frames: impl IntoIterator<Item = SystemSnapshot>) -> Self {
+        Self {
+            frames: frames.into_iter().collect(),
+            current: SystemSnapshot::default(),
+            process_refreshes: Arc::default(),
+        }
+    }
+}
+
+impl SystemSource for ScriptedSystemSource {
+    fn begin_update(&mut self) {
+        if let Some(frame) = self.frames.pop_front() {
+            self.current = frame;
+        }
+    }
+
+    fn refresh_cpu(&mut self) {}
+
+    fn refresh_memory(&mut self) {}
+
+    fn refresh_processes(&mut self) {
+        self.process_refreshes.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn global_cpu_usage(&self) -> f32 {
+        self.current.cpu_usage
+    }
+
+    fn used_memory(&self) -> u64 {
+        self.current.used_memory
+    }
+
+    fn total_memory(&self) -> u64 {
+        self.current.total_memory
+    }
+
+    fn processes(&self) -> &[ProcessInfo] {
+        &self.current.processes
+    }
+}
+
+pub fn process(pid: u32, name: &str, cpu_usage: f32, memory: u64) -> ProcessInfo {
+    ProcessInfo {
+        pid,
+        name: name.to_string(),
+        command_line: name.to_string(),
+        cpu_usage,
+        memory,
+        start_time: pid as u64,
+        is_thread: false,
+    }
+}
@@ -0,0 +1,105 @@
+use crate::error::app_error::{AppError, Result};
+use crate::monitor::system_source::{ProcessInfo, SystemSource};
+use serde::{Deserialize, Serialize};
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+
+/// Everything a `SystemSource` reports at one point in time.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct SystemSnapshot {
+    pub cpu_usage: f32,
+    pub used_memory: u64,
+    pub total_memory: u64,
+    pub processes: Vec<ProcessInfo>,
+}
+
+impl SystemSnapshot {
+    /// Copies the values `source` currently reports, without refreshing it.
+    pub fn capture(source: &dyn SystemSource) -> Self {
+        Self {
+            cpu_usage: source.global_cpu_usage(),
+            used_memory: source.used_memory(),
+            total_memory: source.total_memory(),
+            processes: source.processes().to_vec(),
+        }
+    }
+}
+
+/// One line of a recording: a snapshot and when it was taken, relative to
+/// the first one.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RecordedFrame {
+    pub elapsed_ms: u64,
+    #[serde(flatten)]
+    pub snapshot: SystemSnapshot,
+}
+
+impl RecordedFrame {
+    pub fn write(&self, writer: &mut impl Write) -> io::Result<()> {
+        serde_json::to_writer(&mut *writer, self)?;
+        writeln!(writer)
+    }
+
+    /// Reads a recording with one JSON frame per line. `path` is only used in errors.
+    pub fn read_all(reader: impl BufRead, path: &Path) -> Result<Vec<Self>> {
+        let mut frames = Vec::new();
+        for (index, line) in reader.lines().enumerate() {
+            let line = line.map_err(|e| AppError::io(path, e))?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let frame = serde_json::from_str(&line).map_err(|source| AppError::RecordingParse {
+                path: path.display().to_string(),
+                line: index + 1,
+                source,
+            })?;
+            frames.push(frame);
+        }
+        Ok(frames)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::monitor::scripted_system_source::process;
+
+    fn frame(elapsed_ms: u64, cpu_usage: f32) -> RecordedFrame {
+        RecordedFrame {
+            elapsed_ms,
+            snapshot: SystemSnapshot {
+                cpu_usage,
+                used_memory: 1 << 30,
+                total_memory: 4 << 30,
+                processes: vec![process(1, "init", 0.5, 4096)],
+            },
+        }
+    }
+
+    #[test]
+    fn frames_round_trip_through_json_lines() {
+        let frames = vec![frame(0, 12.5), frame(1000, 80.0)];
+        let mut buffer = Vec::new();
+        for frame in &frames {
+            frame.write(&mut buffer).unwrap();
+        }
+
+        let text = String::from_utf8(buffer.clone()).unwrap();
+        assert_eq!(text.lines().count(), 2);
+        assert_eq!(
+            RecordedFrame::read_all(buffer.as_slice(), Path::new("test.jsonl")).unwrap(),
+            frames
+        );
+    }
+
+    #[test]
+    fn invalid_line_is_reported_with_its_number() {
+        let mut buffer = Vec::new();
+        frame(0, 1.0).write(&mut buffer).unwrap();
+        buffer.extend_from_slice(b"\n{\"elapsed_ms\": \"soon\"}\n");
+
+        let error =
+            RecordedFrame::read_all(buffer.as_slice(), Path::new("test.jsonl")).unwrap_err();
+        assert!(matches!(error, AppError::RecordingParse { line: 3, .. }));
+    }
+}
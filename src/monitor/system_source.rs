@@ -0,0 +1,114 @@
+use serde::{Deserialize, Serialize};
+use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, System, UpdateKind};
+
+/// The values a process-based monitor reads, copied out of the OS process table.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct ProcessInfo {
+    pub pid: u32,
+    pub name: String,
+    /// Arguments joined with spaces.
+    pub command_line: String,
+    pub cpu_usage: f32,
+    /// Resident memory in bytes.
+    pub memory: u64,
+    /// Seconds since the epoch.
+    pub start_time: u64,
+    /// Linux lists threads next to processes; filters skip them.
+    pub is_thread: bool,
+}
+
+/// Where monitors read system metrics from. The live implementation wraps
+/// sysinfo; other implementations feed scripted or recorded values.
+pub trait SystemSource {
+    /// Called once at the start of every `MonitorManager::update_all` cycle.
+    fn begin_update(&mut self) {}
+    fn refresh_cpu(&mut self);
+    fn refresh_memory(&mut self);
+    fn refresh_processes(&mut self);
+    /// Average usage over all cores in percent.
+    fn global_cpu_usage(&self) -> f32;
+    fn used_memory(&self) -> u64;
+    fn total_memory(&self) -> u64;
+    /// Processes as of the last `refresh_processes`.
+    fn processes(&self) -> &[ProcessInfo];
+
+    fn process(&self, pid: u32) -> Option<&ProcessInfo> {
+        self.processes().iter().find(|process| process.pid == pid)
+    }
+}
+
+pub struct SysinfoSource {
+    sys: System,
+    processes: Vec<ProcessInfo>,
+}
+
+impl SysinfoSource {
+    pub fn new() -> Self {
+        let mut source = Self {
+            sys: System::new_all(),
+            processes: Vec::new(),
+        };
+        source.collect_processes();
+        source
+    }
+
+    fn collect_processes(&mut self) {
+        self.processes = self
+            .sys
+            .processes()
+            .values()
+            .map(|process| ProcessInfo {
+                pid: process.pid().as_u32(),
+                name: process.name().to_string_lossy().into_owned(),
+                command_line: process
+                    .cmd()
+                    .iter()
+                    .map(|arg| arg.to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join(" "),
+                cpu_usage: process.cpu_usage(),
+                memory: process.memory(),
+                start_time: process.start_time(),
+                is_thread: process.thread_kind().is_some(),
+            })
+            .collect();
+    }
+}
+
+impl SystemSource for SysinfoSource {
+    fn refresh_cpu(&mut self) {
+        self.sys.refresh_cpu_all();
+    }
+
+    fn refresh_memory(&mut self) {
+        self.sys.refresh_memory();
+    }
+
+    fn refresh_processes(&mut self) {
+        self.sys.refresh_processes_specifics(
+            ProcessesToUpdate::All,
+            true,
+            ProcessRefreshKind::nothing()
+                .with_cpu()
+                .with_memory()
+                .with_cmd(UpdateKind::OnlyIfNotSet),
+        );
+        self.collect_processes();
+    }
+
+    fn global_cpu_usage(&self) -> f32 {
+        self.sys.global_cpu_usage()
+    }
+
+    fn used_memory(&self) -> u64 {
+        self.sys.used_memory()
+    }
+
+    fn total_memory(&self) -> u64 {
+        self.sys.total_memory()
+    }
+
+    fn processes(&self) -> &[ProcessInfo] {
+        &self.processes
+    }
+}
@@ -0,0 +1,190 @@
+use crate::enums::process_action_enum::ProcessActionEnum;
+use crate::error::app_error::{AppError, Result};
+use std::io;
+use sysinfo::Pid;
+
+pub fn apply_action(pid: Pid, action: ProcessActionEnum) -> Result<()> {
+    let outcome = match action {
+        ProcessActionEnum::Terminate => terminate(pid),
+        ProcessActionEnum::Kill => kill(pid),
+        ProcessActionEnum::Renice(nice) => renice(pid, nice),
+    };
+
+    outcome.map_err(|source| AppError::ProcessAction {
+        pid: pid.as_u32(),
+        source,
+    })
+}
+
+/// The process's current nice value, used as the starting point for renicing.
+pub fn current_nice(pid: Pid) -> Result<i32> {
+    nice(pid).map_err(|source| AppError::ProcessAction {
+        pid: pid.as_u32(),
+        source,
+    })
+}
+
+#[cfg(unix)]
+fn terminate(pid: Pid) -> io::Result<()> {
+    send_signal(pid, libc::SIGTERM)
+}
+
+#[cfg(unix)]
+fn kill(pid: Pid) -> io::Result<()> {
+    send_signal(pid, libc::SIGKILL)
+}
+
+#[cfg(unix)]
+fn send_signal(pid: Pid, signal: libc::c_int) -> io::Result<()> {
+    // SAFETY: `kill` only reads its integer arguments.
+    let ret = unsafe { libc::kill(pid.as_u32() as libc::pid_t, signal) };
+    if ret == 0 {
+        Ok(())
+    } else {
+        Err(last_os_error())
+    }
+}
+
+#[cfg(unix)]
+fn renice(pid: Pid, nice: i32) -> io::Result<()> {
+    // SAFETY: `setpriority` only reads its integer arguments.
+    let ret = unsafe { libc::setpriority(libc::PRIO_PROCESS, pid.as_u32() as libc::id_t, nice) };
+    if ret == 0 {
+        Ok(())
+    } else {
+        Err(last_os_error())
+    }
+}
+
+#[cfg(unix)]
+fn nice(pid: Pid) -> io::Result<i32> {
+    // -1 is a valid nice value, so failures can only be told apart through errno.
+    let errno = errno_location().ok_or_else(|| io::Error::from(io::ErrorKind::Unsupported))?;
+    // SAFETY: the location belongs to the current thread, and `getpriority` only
+    // reads its integer arguments.
+    let nice = unsafe {
+        *errno = 0;
+        libc::getpriority(libc::PRIO_PROCESS, pid.as_u32() as libc::id_t)
+    };
+    if nice == -1 && io::Error::last_os_error().raw_os_error() != Some(0) {
+        Err(last_os_error())
+    } else {
+        Ok(nice)
+    }
+}
+
+/// The current thread's errno, on the C libraries whose accessor is known.
+#[cfg(any(target_os = "linux", target_os = "dragonfly"))]
+fn errno_location() -> Option<*mut libc::c_int> {
+    // SAFETY: the accessor has no preconditions.
+    Some(unsafe { libc::__errno_location() })
+}
+
+#[cfg(any(target_os = "android", target_os = "openbsd", target_os = "netbsd"))]
+fn errno_location() -> Option<*mut libc::c_int> {
+    // SAFETY: the accessor has no preconditions.
+    Some(unsafe { libc::__errno() })
+}
+
+#[cfg(any(target_vendor = "apple", target_os = "freebsd"))]
+fn errno_location() -> Option<*mut libc::c_int> {
+    // SAFETY: the accessor has no preconditions.
+    Some(unsafe { libc::__error() })
+}
+
+#[cfg(any(target_os = "solaris", target_os = "illumos"))]
+fn errno_location() -> Option<*mut libc::c_int> {
+    // SAFETY: the accessor has no preconditions.
+    Some(unsafe { libc::___errno() })
+}
+
+#[cfg(target_os = "haiku")]
+fn errno_location() -> Option<*mut libc::c_int> {
+    // SAFETY: the accessor has no preconditions.
+    Some(unsafe { libc::_errnop() })
+}
+
+/// Other Unix targets report reading nice values as unsupported.
+#[cfg(all(
+    unix,
+    not(any(
+        target_os = "linux",
+        target_os = "dragonfly",
+        target_os = "android",
+        target_os = "openbsd",
+        target_os = "netbsd",
+        target_vendor = "apple",
+        target_os = "freebsd",
+        target_os = "solaris",
+        target_os = "illumos",
+        target_os = "haiku",
+    ))
+))]
+fn errno_location() -> Option<*mut libc::c_int> {
+    None
+}
+
+/// `ESRCH` has no dedicated `io::ErrorKind`, so it is mapped to `NotFound` here.
+#[cfg(unix)]
+fn last_os_error() -> io::Error {
+    let error = io::Error::last_os_error();
+    if error.raw_os_error() == Some(libc::ESRCH) {
+        io::Error::from(io::ErrorKind::NotFound)
+    } else {
+        error
+    }
+}
+
+/// Windows has no graceful termination signal, so terminating falls back to killing.
+#[cfg(not(unix))]
+fn terminate(pid: Pid) -> io::Result<()> {
+    kill(pid)
+}
+
+#[cfg(not(unix))]
+fn kill(pid: Pid) -> io::Result<()> {
+    use sysinfo::{ProcessesToUpdate, System};
+
+    let mut sys = System::new();
+    sys.refresh_processes(ProcessesToUpdate::Some(&[pid]), true);
+    let process = sys
+        .process(pid)
+        .ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))?;
+
+    if process.kill() {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
+
+#[cfg(not(unix))]
+fn nice(_pid: Pid) -> io::Result<i32> {
+    Err(io::Error::from(io::ErrorKind::Unsupported))
+}
+
+#[cfg(not(unix))]
+fn renice(_pid: Pid, _nice: i32) -> io::Result<()> {
+    Err(io::Error::from(io::ErrorKind::Unsupported))
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_nice_value_of_own_process() {
+        let pid = Pid::from_u32(std::process::id());
+        // SAFETY: `nice(0)` only reads the current nice value.
+        let expected = unsafe { libc::nice(0) };
+
+        assert_eq!(current_nice(pid).unwrap(), expected);
+    }
+
+    #[test]
+    fn missing_process_is_not_found() {
+        let error = nice(Pid::from_u32(u32::MAX / 2)).unwrap_err();
+
+        assert_eq!(error.kind(), io::ErrorKind::NotFound);
+    }
+}
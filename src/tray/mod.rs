@@ -1,3 +1,5 @@
 pub mod cpu_usage_tray_item;
+pub mod pinned_process_tray_item;
+pub mod process_group_tray_item;
 pub mod ram_usage_tray_item;
 pub mod tray;
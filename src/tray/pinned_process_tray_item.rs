@@ -0,0 +1,17 @@
+use crate::{enums::monitor_type_enum::MonitorTypeEnum, tray::tray::TrayItem};
+use tray_icon::TrayIcon;
+
+pub struct PinnedProcessTrayItem {
+    pub icon: TrayIcon,
+    pub id: u32,
+}
+
+impl TrayItem for PinnedProcessTrayItem {
+    fn get_type(&self) -> MonitorTypeEnum {
+        MonitorTypeEnum::PinnedProcess(self.id)
+    }
+
+    fn icon(&self) -> &TrayIcon {
+        &self.icon
+    }
+}
@@ -0,0 +1,17 @@
+use crate::{enums::monitor_type_enum::MonitorTypeEnum, tray::tray::TrayItem};
+use tray_icon::TrayIcon;
+
+pub struct ProcessGroupTrayItem {
+    pub icon: TrayIcon,
+    pub id: u32,
+}
+
+impl TrayItem for ProcessGroupTrayItem {
+    fn get_type(&self) -> MonitorTypeEnum {
+        MonitorTypeEnum::ProcessGroup(self.id)
+    }
+
+    fn icon(&self) -> &TrayIcon {
+        &self.icon
+    }
+}
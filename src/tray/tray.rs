@@ -1,23 +1,41 @@
-use crate::enums::{monitor_type_enum::MonitorTypeEnum, tray_menu_event_enum::TrayMenuEventEnum};
+use crate::config::app_config::AppConfig;
+use crate::enums::{
+    monitor_details_enum::MonitorDetailsEnum, monitor_type_enum::MonitorTypeEnum,
+    monitor_unit_enum::MonitorUnitEnum, tray_menu_event_enum::TrayMenuEventEnum,
+};
 use crate::error::app_error::Result;
 use crate::i18n::i18n_manager::I18nManager;
+use crate::monitor::metric_history::MetricHistory;
+use crate::monitor::monitor::MonitorSample;
 use crate::tray::cpu_usage_tray_item::CpuUsageTrayItem;
+use crate::tray::pinned_process_tray_item::PinnedProcessTrayItem;
+use crate::tray::process_group_tray_item::ProcessGroupTrayItem;
 use crate::tray::ram_usage_tray_item::RamUsageTrayItem;
 use crossbeam_channel::Receiver;
+use fluent::fluent_args;
+use std::cell::RefCell;
 use std::collections::HashMap;
-use strum::IntoEnumIterator;
+use std::time::{Duration, Instant};
 use tray_icon::{
     Icon, TrayIcon, TrayIconBuilder,
-    menu::{Menu, MenuEvent, MenuId, MenuItem, PredefinedMenuItem},
+    menu::{CheckMenuItem, Menu, MenuEvent, MenuId, MenuItem, PredefinedMenuItem, Submenu},
 };
 
+const GIB: f64 = 1024.0 * 1024.0 * 1024.0;
+/// Windows cuts tooltips off after 127 UTF-16 units (`NOTIFYICONDATAW::szTip`).
+const TOOLTIP_MAX_LEN: usize = 127;
+
 pub trait Tray {
     fn update(
         &self,
-        active_monitors: &std::collections::HashSet<MonitorTypeEnum>,
+        app_config: &AppConfig,
         i18n: &I18nManager,
-        stats: &[(MonitorTypeEnum, f32)],
+        stats: &[MonitorSample],
+        history: &MetricHistory,
     ) -> Result<()>;
+    fn monitor_types(&self) -> Vec<MonitorTypeEnum>;
+    /// Brings the checkable menu items in line with `app_config`.
+    fn sync_menu_state(&self, app_config: &AppConfig);
 }
 
 pub trait TrayItem {
@@ -27,10 +45,17 @@ pub trait TrayItem {
 
 pub struct SystemTray {
     items: Vec<Box<dyn TrayItem>>,
+    menu: Menu,
+    /// Disabled items at the top of the menu showing the current value of each active monitor.
+    value_items: RefCell<Vec<(MonitorTypeEnum, MenuItem)>>,
+    values_separator: PredefinedMenuItem,
+    monitor_check_items: Vec<(MonitorTypeEnum, CheckMenuItem)>,
+    pause_item: CheckMenuItem,
 }
 
 impl SystemTray {
     pub fn new(
+        app_config: &AppConfig,
         i18n: &I18nManager,
     ) -> Result<(
         Self,
@@ -40,16 +65,49 @@ impl SystemTray {
         let mut id_map = HashMap::new();
         let menu = Menu::new();
         let settings_item = MenuItem::new(i18n.get_message("tray-settings-item"), true, None);
+        let dashboard_item = MenuItem::new(i18n.get_message("tray-dashboard-item"), true, None);
         let quit_item = MenuItem::new(i18n.get_message("tray-shutdown-item"), true, None);
+        let pause_item = CheckMenuItem::new(
+            i18n.get_message("tray-pause-item"),
+            true,
+            app_config.general.sampling_paused,
+            None,
+        );
+        let monitors_submenu = Submenu::new(i18n.get_message("tray-monitors-submenu"), true);
+        let mut monitor_check_items = Vec::new();
+        for monitor_type in app_config.monitor_types() {
+            let check_item = CheckMenuItem::new(
+                tooltip_title(monitor_type, app_config, i18n),
+                true,
+                app_config.is_monitor_active(monitor_type),
+                None,
+            );
+            monitors_submenu.append(&check_item)?;
+            id_map.insert(
+                check_item.id().clone(),
+                TrayMenuEventEnum::ToggleMonitor(monitor_type),
+            );
+            monitor_check_items.push((monitor_type, check_item));
+        }
+
         id_map.insert(settings_item.id().clone(), TrayMenuEventEnum::Settings);
+        id_map.insert(dashboard_item.id().clone(), TrayMenuEventEnum::Dashboard);
+        id_map.insert(pause_item.id().clone(), TrayMenuEventEnum::TogglePause);
         id_map.insert(quit_item.id().clone(), TrayMenuEventEnum::Quit);
-        menu.append_items(&[&settings_item, &PredefinedMenuItem::separator(), &quit_item])?;
+        menu.append_items(&[
+            &monitors_submenu,
+            &pause_item,
+            &PredefinedMenuItem::separator(),
+            &settings_item,
+            &dashboard_item,
+            &PredefinedMenuItem::separator(),
+            &quit_item,
+        ])?;
 
         let mut items: Vec<Box<dyn TrayItem>> = Vec::new();
 
-        for monitor_type in MonitorTypeEnum::iter() {
-            let tooltip_key = monitor_type.tray_tooltip_key();
-            let tooltip = i18n.get_message(tooltip_key);
+        for monitor_type in app_config.monitor_types() {
+            let tooltip = fit_tooltip(&tooltip_title(monitor_type, app_config, i18n), None, None);
 
             let icon = TrayIconBuilder::new()
                 .with_menu(Box::new(menu.clone()))
@@ -60,43 +118,109 @@ impl SystemTray {
             let tray_item: Box<dyn TrayItem> = match monitor_type {
                 MonitorTypeEnum::CpuUsage => Box::new(CpuUsageTrayItem { icon }),
                 MonitorTypeEnum::RamUsage => Box::new(RamUsageTrayItem { icon }),
+                MonitorTypeEnum::PinnedProcess(id) => Box::new(PinnedProcessTrayItem { icon, id }),
+                MonitorTypeEnum::ProcessGroup(id) => Box::new(ProcessGroupTrayItem { icon, id }),
             };
             items.push(tray_item);
         }
 
-        Ok((Self { items }, MenuEvent::receiver().clone(), id_map))
+        let tray = Self {
+            items,
+            menu,
+            value_items: RefCell::new(Vec::new()),
+            values_separator: PredefinedMenuItem::separator(),
+            monitor_check_items,
+            pause_item,
+        };
+
+        Ok((tray, MenuEvent::receiver().clone(), id_map))
+    }
+
+    fn update_menu_values(
+        &self,
+        app_config: &AppConfig,
+        i18n: &I18nManager,
+        stats: &[MonitorSample],
+    ) -> Result<()> {
+        let mut value_items = self.value_items.borrow_mut();
+
+        let layout_changed = value_items
+            .iter()
+            .map(|(monitor_type, _)| *monitor_type)
+            .ne(stats.iter().map(|sample| sample.monitor_type));
+
+        if layout_changed {
+            if !value_items.is_empty() {
+                self.menu.remove(&self.values_separator)?;
+            }
+            for (_, item) in value_items.drain(..) {
+                self.menu.remove(&item)?;
+            }
+
+            for (position, sample) in stats.iter().enumerate() {
+                let item = MenuItem::new("", false, None);
+                self.menu.insert(&item, position)?;
+                value_items.push((sample.monitor_type, item));
+            }
+            if !value_items.is_empty() {
+                self.menu
+                    .insert(&self.values_separator, value_items.len())?;
+            }
+        }
+
+        for ((_, item), sample) in value_items.iter().zip(stats) {
+            item.set_text(menu_value_text(sample, app_config, i18n));
+        }
+        Ok(())
     }
 }
 
 impl Tray for SystemTray {
     fn update(
         &self,
-        active_monitors: &std::collections::HashSet<MonitorTypeEnum>,
+        app_config: &AppConfig,
         i18n: &I18nManager,
-        stats: &[(MonitorTypeEnum, f32)],
+        stats: &[MonitorSample],
+        history: &MetricHistory,
     ) -> Result<()> {
-        let stats_map: HashMap<MonitorTypeEnum, f32> = stats.iter().cloned().collect();
+        let stats_map: HashMap<MonitorTypeEnum, &MonitorSample> = stats
+            .iter()
+            .map(|sample| (sample.monitor_type, sample))
+            .collect();
 
         for item in &self.items {
             let monitor_type = item.get_type();
-            let is_visible = active_monitors.contains(&monitor_type);
+            let is_visible = app_config.is_monitor_active(monitor_type);
 
             item.icon().set_visible(is_visible)?;
 
-            if is_visible {
-                if let Some(value) = stats_map.get(&monitor_type) {
-                    let unit = monitor_type.unit();
+            if is_visible && let Some(sample) = stats_map.get(&monitor_type) {
+                let label = monitor_type.icon_label(i18n, app_config);
+                let value_text = sample.unit.format_icon_value(sample.value, i18n);
 
-                    let label_key = monitor_type.icon_label_key();
-                    let label = i18n.get_message(label_key);
+                let rgba = generate_icon_rgba(&label, &value_text)?;
+                let icon_img = Icon::from_rgba(rgba, 32, 32)?;
+                item.icon().set_icon(Some(icon_img))?;
 
-                    let rgba = generate_icon_rgba(&label, *value, unit);
-                    let icon_img = Icon::from_rgba(rgba, 32, 32)?;
-                    item.icon().set_icon(Some(icon_img))?;
-                }
+                let tooltip = tooltip_text(sample, app_config, i18n, history);
+                item.icon().set_tooltip(Some(tooltip))?;
             }
         }
-        Ok(())
+
+        self.sync_menu_state(app_config);
+        self.update_menu_values(app_config, i18n, stats)
+    }
+
+    fn monitor_types(&self) -> Vec<MonitorTypeEnum> {
+        self.items.iter().map(|item| item.get_type()).collect()
+    }
+
+    fn sync_menu_state(&self, app_config: &AppConfig) {
+        for (monitor_type, check_item) in &self.monitor_check_items {
+            check_item.set_checked(app_config.is_monitor_active(*monitor_type));
+        }
+        self.pause_item
+            .set_checked(app_config.general.sampling_paused);
     }
 }
 
@@ -106,7 +230,151 @@ impl Drop for SystemTray {
     }
 }
 
-fn generate_icon_rgba(label: &str, value: f32, unit: &str) -> Vec<u8> {
+fn menu_value_text(sample: &MonitorSample, app_config: &AppConfig, i18n: &I18nManager) -> String {
+    let label = sample.monitor_type.icon_label(i18n, app_config);
+    let value = sample.unit.format_value(sample.value, i18n);
+    match sample.details {
+        MonitorDetailsEnum::Memory { used, total } => i18n.get_message_with_args(
+            "tray-menu-value-memory",
+            &fluent_args![
+                "label" => label,
+                "value" => value,
+                "used" => i18n.format_decimal(used as f64 / GIB, 1),
+                "total" => i18n.format_decimal(total as f64 / GIB, 1),
+            ],
+        ),
+        MonitorDetailsEnum::ProcessGroup { process_count, .. } => i18n.get_message_with_args(
+            "tray-menu-value-process-group",
+            &fluent_args![
+                "label" => label,
+                "value" => value,
+                "count" => process_count,
+            ],
+        ),
+        MonitorDetailsEnum::None => i18n.get_message_with_args(
+            "tray-menu-value",
+            &fluent_args!["label" => label, "value" => value],
+        ),
+    }
+}
+
+fn tooltip_text(
+    sample: &MonitorSample,
+    app_config: &AppConfig,
+    i18n: &I18nManager,
+    history: &MetricHistory,
+) -> String {
+    let unit = sample.unit;
+    let value_line = i18n.get_message_with_args(
+        "tray-tooltip-value",
+        &fluent_args![
+            "title" => tooltip_title(sample.monitor_type, app_config, i18n),
+            "value" => unit.format_value(sample.value, i18n),
+        ],
+    );
+
+    let details_line = match sample.details {
+        MonitorDetailsEnum::Memory { used, total } => Some(i18n.get_message_with_args(
+            "tray-tooltip-memory",
+            &fluent_args![
+                "used" => i18n.format_decimal(used as f64 / GIB, 1),
+                "total" => i18n.format_decimal(total as f64 / GIB, 1),
+            ],
+        )),
+        MonitorDetailsEnum::ProcessGroup {
+            cpu_usage,
+            memory,
+            process_count,
+        } => Some(i18n.get_message_with_args(
+            "tray-tooltip-process-group-details",
+            &fluent_args![
+                "cpu" => MonitorUnitEnum::Percent.format_value(cpu_usage, i18n),
+                "memory" => MonitorUnitEnum::Bytes.format_value(memory as f32, i18n),
+                "count" => process_count,
+            ],
+        )),
+        MonitorDetailsEnum::None => None,
+    };
+
+    let minutes = app_config.tooltip.statistics_window_minutes;
+    let statistics_line = history
+        .series(sample.monitor_type)
+        .and_then(|series| series.statistics(Instant::now(), Duration::from_secs(minutes * 60)))
+        .map(|statistics| {
+            i18n.get_message_with_args(
+                "tray-tooltip-statistics",
+                &fluent_args![
+                    "min" => unit.format_value(statistics.min, i18n),
+                    "avg" => unit.format_value(statistics.avg, i18n),
+                    "max" => unit.format_value(statistics.max, i18n),
+                    "minutes" => minutes,
+                ],
+            )
+        });
+
+    fit_tooltip(
+        &value_line,
+        details_line.as_deref(),
+        statistics_line.as_deref(),
+    )
+}
+
+/// Joins the tooltip lines within `TOOLTIP_MAX_LEN`. The details line is
+/// dropped first, then the statistics; a value line that is too long on its
+/// own is shortened.
+fn fit_tooltip(
+    value_line: &str,
+    details_line: Option<&str>,
+    statistics_line: Option<&str>,
+) -> String {
+    let candidates = [
+        [Some(value_line), details_line, statistics_line],
+        [Some(value_line), None, statistics_line],
+        [Some(value_line), None, None],
+    ];
+    for lines in candidates {
+        let text = lines.into_iter().flatten().collect::<Vec<_>>().join("\n");
+        if text.encode_utf16().count() <= TOOLTIP_MAX_LEN {
+            return text;
+        }
+    }
+
+    let mut shortened = String::new();
+    let mut len = 1; // The ellipsis.
+    for c in value_line.chars() {
+        len += c.len_utf16();
+        if len > TOOLTIP_MAX_LEN {
+            break;
+        }
+        shortened.push(c);
+    }
+    shortened.push('…');
+    shortened
+}
+
+fn tooltip_title(
+    monitor_type: MonitorTypeEnum,
+    app_config: &AppConfig,
+    i18n: &I18nManager,
+) -> String {
+    let title = i18n.get_message(monitor_type.tray_tooltip_key());
+    let description = match monitor_type {
+        MonitorTypeEnum::PinnedProcess(id) => app_config
+            .pinned_process(id)
+            .map(|pinned| pinned.rule.to_string()),
+        MonitorTypeEnum::ProcessGroup(id) => app_config
+            .process_group(id)
+            .map(|group| group.filter.to_string()),
+        _ => None,
+    };
+
+    match description {
+        Some(description) => format!("{}: {}", title, description),
+        None => title,
+    }
+}
+
+fn generate_icon_rgba(label: &str, value_text: &str) -> Result<Vec<u8>> {
     use ab_glyph::{FontRef, PxScale};
     use image::{Rgba, RgbaImage};
     use imageproc::drawing::draw_text_mut;
@@ -115,12 +383,78 @@ fn generate_icon_rgba(label: &str, value: f32, unit: &str) -> Vec<u8> {
     let height = 32;
     let mut img = RgbaImage::from_pixel(width, height, Rgba([0, 0, 0, 0]));
     let font_data = include_bytes!("../../resources/fonts/DejaVuSansMono.ttf");
-    let font = FontRef::try_from_slice(font_data).unwrap();
+    let font = FontRef::try_from_slice(font_data)?;
     let text_color = Rgba([255, 255, 255, 255]);
     let scale_label = PxScale::from(16.0);
     draw_text_mut(&mut img, text_color, 2, 0, scale_label, &font, label);
     let scale_value = PxScale::from(16.0);
-    let value_text = format!("{:.0}{}", value, unit);
-    draw_text_mut(&mut img, text_color, 2, 16, scale_value, &font, &value_text);
-    img.into_raw()
+    draw_text_mut(&mut img, text_color, 2, 16, scale_value, &font, value_text);
+    Ok(img.into_raw())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ICON_SIZE: usize = 32;
+
+    /// Rows that contain at least one non-transparent pixel.
+    fn drawn_rows(rgba: &[u8]) -> Vec<usize> {
+        rgba.chunks(ICON_SIZE * 4)
+            .enumerate()
+            .filter(|(_, row)| row.chunks(4).any(|pixel| pixel[3] > 0))
+            .map(|(y, _)| y)
+            .collect()
+    }
+
+    #[test]
+    fn tooltip_keeps_all_lines_that_fit() {
+        let tooltip = fit_tooltip("CPU: 12%", Some("details"), Some("stats"));
+        assert_eq!(tooltip, "CPU: 12%\ndetails\nstats");
+    }
+
+    #[test]
+    fn tooltip_drops_details_before_statistics() {
+        let value = "Process group: ^(chrome|chromium|firefox|thunderbird)$: 140%";
+        let details = "CPU 140%, memory 3.4 GiB, 42 processes";
+        let statistics = "Last 5 min: min 12%, avg 80%, max 190%";
+
+        let tooltip = fit_tooltip(value, Some(details), Some(statistics));
+
+        assert_eq!(tooltip, format!("{}\n{}", value, statistics));
+    }
+
+    #[test]
+    fn tooltip_shortens_an_overlong_value_line() {
+        let value = format!("Process group: {}: 5%", "ż".repeat(200));
+
+        let tooltip = fit_tooltip(&value, Some("details"), None);
+
+        assert_eq!(tooltip.encode_utf16().count(), TOOLTIP_MAX_LEN);
+        assert!(tooltip.starts_with("Process group: żż"));
+        assert!(tooltip.ends_with('…'));
+    }
+
+    #[test]
+    fn icon_is_32x32_rgba() {
+        let rgba = generate_icon_rgba("CPU", "42%").unwrap();
+        assert_eq!(rgba.len(), ICON_SIZE * ICON_SIZE * 4);
+    }
+
+    #[test]
+    fn empty_text_leaves_icon_transparent() {
+        let rgba = generate_icon_rgba("", "").unwrap();
+        assert!(drawn_rows(&rgba).is_empty());
+    }
+
+    #[test]
+    fn label_and_value_are_drawn_on_separate_halves() {
+        let label_rows = drawn_rows(&generate_icon_rgba("RAM", "").unwrap());
+        let value_rows = drawn_rows(&generate_icon_rgba("", "73%").unwrap());
+
+        assert!(!label_rows.is_empty());
+        assert!(!value_rows.is_empty());
+        assert!(label_rows.iter().all(|&y| y < ICON_SIZE / 2));
+        assert!(value_rows.iter().all(|&y| y >= ICON_SIZE / 2));
+    }
 }
@@ -0,0 +1,111 @@
+use crate::{
+    config::app_config::AppConfig,
+    enums::{chart_time_window_enum::ChartTimeWindowEnum, monitor_unit_enum::MonitorUnitEnum},
+    i18n::i18n_manager::I18nManager,
+    monitor::metric_history::MetricHistory,
+};
+use eframe::egui;
+use egui_plot::{Line, Plot, PlotPoints};
+use fluent::fluent_args;
+use std::time::Instant;
+use strum::IntoEnumIterator;
+
+pub struct DashboardView {
+    time_window: ChartTimeWindowEnum,
+}
+
+impl DashboardView {
+    pub const DEFAULT_SIZE: [f32; 2] = [560.0, 480.0];
+    const CHART_HEIGHT: f32 = 120.0;
+
+    pub fn new() -> Self {
+        Self {
+            time_window: ChartTimeWindowEnum::default(),
+        }
+    }
+
+    pub fn draw(
+        &mut self,
+        ui: &mut egui::Ui,
+        i18n: &I18nManager,
+        app_config: &AppConfig,
+        history: &MetricHistory,
+    ) {
+        ui.horizontal(|ui| {
+            ui.label(i18n.get_message("dashboard-time-window-label"));
+            for time_window in ChartTimeWindowEnum::iter() {
+                ui.selectable_value(
+                    &mut self.time_window,
+                    time_window,
+                    i18n.get_message(time_window.label_key()),
+                );
+            }
+        });
+        ui.separator();
+
+        let now = Instant::now();
+        let window = self.time_window.duration();
+
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            for monitor_type in app_config.monitor_types() {
+                if !app_config.is_monitor_active(monitor_type) {
+                    continue;
+                }
+
+                let label = monitor_type.icon_label(i18n, app_config);
+                let Some(series) = history.series(monitor_type) else {
+                    ui.strong(&label);
+                    ui.label(i18n.get_message("dashboard-no-data"));
+                    ui.add_space(10.0);
+                    continue;
+                };
+
+                let unit = series.unit;
+                let points: PlotPoints = series
+                    .points_since(now, window)
+                    .map(|point| {
+                        let seconds_ago = now.saturating_duration_since(point.at).as_secs_f64();
+                        [-seconds_ago, point.value as f64]
+                    })
+                    .collect();
+
+                let current = series
+                    .points
+                    .back()
+                    .map(|point| unit.format_value(point.value, i18n))
+                    .unwrap_or_default();
+                ui.strong(format!("{} · {}", label, current));
+
+                let mut plot = Plot::new(("dashboard_chart", monitor_type))
+                    .height(Self::CHART_HEIGHT)
+                    .allow_drag(false)
+                    .allow_zoom(false)
+                    .allow_scroll(false)
+                    .include_x(-window.as_secs_f64())
+                    .include_x(0.0)
+                    .include_y(0.0)
+                    .x_axis_formatter(|mark, _range| i18n.format_decimal(mark.value, 0))
+                    .y_axis_formatter(move |mark, _range| {
+                        unit.format_value(mark.value as f32, i18n)
+                    })
+                    .label_formatter(|_name, point| {
+                        i18n.get_message_with_args(
+                            "dashboard-point-label",
+                            &fluent_args![
+                                "value" => unit.format_value(point.y as f32, i18n),
+                                "seconds" => i18n.format_decimal(-point.x, 0),
+                            ],
+                        )
+                    });
+                if unit == MonitorUnitEnum::Percent {
+                    plot = plot.include_y(100.0);
+                }
+
+                plot.show(ui, |plot_ui| {
+                    plot_ui.line(Line::new(label.clone(), points));
+                });
+                ui.add_space(10.0);
+            }
+        });
+    }
+}
@@ -0,0 +1,63 @@
+use crate::i18n::i18n_manager::I18nManager;
+use eframe::{NativeOptions, egui};
+
+/// Shows a fatal startup error in its own window and blocks until it is closed.
+/// The app runs without a console on Windows, so this is the only place the
+/// user would see why it didn't start.
+pub fn show_startup_error(i18n: &I18nManager, summary: &str, details: &str) {
+    let title = i18n.get_message("startup-error-title");
+    let dialog = ErrorDialog {
+        title: title.clone(),
+        summary: summary.to_string(),
+        details: details.to_string(),
+        close_label: i18n.get_message("startup-error-close-button"),
+    };
+
+    let native_options = NativeOptions {
+        viewport: egui::ViewportBuilder::default()
+            .with_title(title.clone())
+            .with_inner_size(ErrorDialog::DEFAULT_SIZE),
+        ..Default::default()
+    };
+
+    if let Err(e) = eframe::run_native(&title, native_options, Box::new(|_cc| Ok(Box::new(dialog))))
+    {
+        tracing::error!(error = %e, "Failed to show startup error dialog");
+    }
+}
+
+struct ErrorDialog {
+    title: String,
+    summary: String,
+    details: String,
+    close_label: String,
+}
+
+impl ErrorDialog {
+    const DEFAULT_SIZE: [f32; 2] = [480.0, 240.0];
+}
+
+impl eframe::App for ErrorDialog {
+    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        egui::TopBottomPanel::bottom("error_dialog_buttons").show(ctx, |ui| {
+            ui.add_space(5.0);
+            if ui.button(&self.close_label).clicked() {
+                ctx.send_viewport_cmd(egui::ViewportCommand::Close);
+            }
+            ui.add_space(5.0);
+        });
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.heading(&self.title);
+            ui.add_space(5.0);
+            ui.label(&self.summary);
+            ui.add_space(5.0);
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                ui.add(
+                    egui::Label::new(egui::RichText::new(&self.details).monospace())
+                        .selectable(true),
+                );
+            });
+        });
+    }
+}
@@ -1,2 +1,6 @@
 pub mod components;
+pub mod dashboard_view;
+pub mod error_dialog;
+pub mod process_view;
 pub mod ui;
+pub mod viewport;
@@ -0,0 +1,302 @@
+use crate::{
+    config::app_config::{
+        AppConfig, PinnedProcessConfig, ProcessGroupConfig, default_process_label,
+    },
+    enums::{
+        process_action_enum::ProcessActionEnum, process_filter_enum::ProcessFilterEnum,
+        process_match_rule_enum::ProcessMatchRuleEnum, process_metric_enum::ProcessMetricEnum,
+    },
+    error::app_error::AppError,
+    i18n::i18n_manager::I18nManager,
+    process::process_control,
+};
+use eframe::egui;
+use std::io;
+use std::time::{Duration, Instant};
+use sysinfo::{Pid, ProcessesToUpdate, System};
+
+struct ProcessRow {
+    pid: Pid,
+    name: String,
+    cpu_usage: f32,
+    memory: u64,
+}
+
+struct PendingAction {
+    pid: Pid,
+    name: String,
+    action: ProcessActionEnum,
+}
+
+pub struct ProcessView {
+    sys: System,
+    rows: Vec<ProcessRow>,
+    /// `None` until the first refresh after the view is opened.
+    next_refresh: Option<Instant>,
+    pending_action: Option<PendingAction>,
+    error_message: Option<String>,
+}
+
+impl ProcessView {
+    pub const DEFAULT_SIZE: [f32; 2] = [520.0, 420.0];
+
+    pub fn new() -> Self {
+        Self {
+            sys: System::new(),
+            rows: Vec::new(),
+            next_refresh: None,
+            pending_action: None,
+            error_message: None,
+        }
+    }
+
+    /// Called when the view is opened, so it starts from fresh measurements.
+    pub fn reset(&mut self) {
+        self.next_refresh = None;
+    }
+
+    fn refresh_if_due(&mut self, refresh_interval: Duration) {
+        if self.next_refresh.is_some_and(|next| Instant::now() < next) {
+            return;
+        }
+
+        // CPU usage is measured between two refreshes, so the first one is
+        // followed shortly by another instead of a whole interval later.
+        let delay = if self.next_refresh.is_none() {
+            sysinfo::MINIMUM_CPU_UPDATE_INTERVAL
+        } else {
+            refresh_interval
+        };
+        self.sys.refresh_processes(ProcessesToUpdate::All, true);
+        // Threads are listed as processes of their own on Linux, but actions
+        // on them would affect the whole process or only the thread.
+        self.rows = self
+            .sys
+            .processes()
+            .values()
+            .filter(|process| process.thread_kind().is_none())
+            .map(|process| ProcessRow {
+                pid: process.pid(),
+                name: process.name().to_string_lossy().into_owned(),
+                cpu_usage: process.cpu_usage(),
+                memory: process.memory(),
+            })
+            .collect();
+        self.rows
+            .sort_by(|a, b| b.cpu_usage.total_cmp(&a.cpu_usage));
+        self.next_refresh = Some(Instant::now() + delay);
+    }
+
+    pub fn draw(&mut self, ui: &mut egui::Ui, i18n: &I18nManager, app_config: &mut AppConfig) {
+        self.refresh_if_due(Duration::from_secs(
+            app_config.refresh.default_refresh_seconds,
+        ));
+
+        if let Some(message) = &self.error_message {
+            let mut dismissed = false;
+            ui.horizontal(|ui| {
+                ui.colored_label(ui.visuals().error_fg_color, message);
+                dismissed = ui.small_button("✖").clicked();
+            });
+            if dismissed {
+                self.error_message = None;
+            }
+            ui.separator();
+        }
+
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            egui::Grid::new("process_grid")
+                .num_columns(4)
+                .spacing([20.0, 4.0])
+                .striped(true)
+                .show(ui, |ui| {
+                    ui.strong(i18n.get_message("process-column-pid"));
+                    ui.strong(i18n.get_message("process-column-name"));
+                    ui.strong(i18n.get_message("process-column-cpu"));
+                    ui.strong(i18n.get_message("process-column-memory"));
+                    ui.end_row();
+
+                    for row in &self.rows {
+                        ui.label(row.pid.to_string());
+                        ui.add(egui::Label::new(&row.name).sense(egui::Sense::click()))
+                            .context_menu(|ui| {
+                                for action in Self::available_actions() {
+                                    if ui.button(i18n.get_message(action.label_key())).clicked() {
+                                        match Self::initial_action(row.pid, action) {
+                                            Ok(action) => {
+                                                self.pending_action = Some(PendingAction {
+                                                    pid: row.pid,
+                                                    name: row.name.clone(),
+                                                    action,
+                                                });
+                                            }
+                                            Err(e) => {
+                                                tracing::warn!(error = %e, "Failed to read process state");
+                                                self.error_message =
+                                                    Some(i18n.get_message(error_message_key(&e)));
+                                            }
+                                        }
+                                        ui.close();
+                                    }
+                                }
+
+                                ui.separator();
+                                if ui.button(i18n.get_message("process-pin-by-name")).clicked() {
+                                    let pattern = format!("^{}$", regex::escape(&row.name));
+                                    Self::pin(
+                                        app_config,
+                                        &row.name,
+                                        ProcessMatchRuleEnum::NamePattern(pattern),
+                                    );
+                                    ui.close();
+                                }
+                                if ui.button(i18n.get_message("process-pin-by-pid")).clicked() {
+                                    Self::pin(
+                                        app_config,
+                                        &row.name,
+                                        ProcessMatchRuleEnum::Pid(row.pid.as_u32()),
+                                    );
+                                    ui.close();
+                                }
+                                if ui
+                                    .button(i18n.get_message("process-group-by-name"))
+                                    .clicked()
+                                {
+                                    Self::add_group(app_config, &row.name);
+                                    ui.close();
+                                }
+                            });
+                        ui.label(i18n.format_decimal(row.cpu_usage as f64, 1));
+                        ui.label(i18n.format_bytes(row.memory as f64));
+                        ui.end_row();
+                    }
+                });
+        });
+
+        self.draw_confirmation(ui.ctx(), i18n);
+    }
+
+    fn pin(app_config: &mut AppConfig, name: &str, rule: ProcessMatchRuleEnum) {
+        if app_config
+            .pinned_processes
+            .iter()
+            .any(|pinned| pinned.rule == rule)
+        {
+            return;
+        }
+
+        app_config.add_pinned_process(PinnedProcessConfig {
+            id: 0,
+            label: default_process_label(name),
+            rule,
+            metric: ProcessMetricEnum::CpuUsage,
+            enabled: true,
+        });
+    }
+
+    fn add_group(app_config: &mut AppConfig, name: &str) {
+        let filter = ProcessFilterEnum::NamePattern(format!("^{}$", regex::escape(name)));
+        if app_config
+            .process_groups
+            .iter()
+            .any(|group| group.filter == filter)
+        {
+            return;
+        }
+
+        app_config.add_process_group(ProcessGroupConfig {
+            id: 0,
+            label: default_process_label(name),
+            filter,
+            metric: ProcessMetricEnum::CpuUsage,
+            enabled: true,
+        });
+    }
+
+    fn available_actions() -> Vec<ProcessActionEnum> {
+        let mut actions = vec![ProcessActionEnum::Terminate, ProcessActionEnum::Kill];
+        if cfg!(unix) {
+            actions.push(ProcessActionEnum::Renice(0));
+        }
+        actions
+    }
+
+    /// Renicing starts from the current nice value, so confirming without edits changes nothing.
+    fn initial_action(pid: Pid, action: ProcessActionEnum) -> Result<ProcessActionEnum, AppError> {
+        match action {
+            ProcessActionEnum::Renice(_) => {
+                process_control::current_nice(pid).map(ProcessActionEnum::Renice)
+            }
+            action => Ok(action),
+        }
+    }
+
+    fn draw_confirmation(&mut self, ctx: &egui::Context, i18n: &I18nManager) {
+        let Some(pending) = &mut self.pending_action else {
+            return;
+        };
+
+        let mut confirmed = false;
+        let mut cancelled = false;
+
+        let modal = egui::Modal::new(egui::Id::new("process_action_modal")).show(ctx, |ui| {
+            ui.heading(i18n.get_message("process-confirm-title"));
+            ui.add_space(5.0);
+            ui.label(i18n.get_message(pending.action.confirm_key()));
+            ui.label(format!("{} ({})", pending.name, pending.pid));
+
+            if let ProcessActionEnum::Renice(nice) = &mut pending.action {
+                ui.horizontal(|ui| {
+                    ui.label(i18n.get_message("process-nice-label"));
+                    ui.add(
+                        egui::DragValue::new(nice)
+                            .range(ProcessActionEnum::MIN_NICE..=ProcessActionEnum::MAX_NICE),
+                    );
+                });
+            }
+
+            ui.add_space(10.0);
+            ui.horizontal(|ui| {
+                confirmed = ui
+                    .button(i18n.get_message("process-confirm-button"))
+                    .clicked();
+                cancelled = ui
+                    .button(i18n.get_message("process-cancel-button"))
+                    .clicked();
+            });
+        });
+
+        if modal.should_close() {
+            cancelled = true;
+        }
+
+        if confirmed {
+            if let Some(pending) = self.pending_action.take() {
+                match process_control::apply_action(pending.pid, pending.action) {
+                    Ok(()) => {
+                        self.error_message = None;
+                        self.next_refresh = Some(Instant::now());
+                    }
+                    Err(e) => {
+                        tracing::warn!(error = %e, "Process action failed");
+                        self.error_message = Some(i18n.get_message(error_message_key(&e)));
+                    }
+                }
+            }
+        } else if cancelled {
+            self.pending_action = None;
+        }
+    }
+}
+
+fn error_message_key(error: &AppError) -> &'static str {
+    match error {
+        AppError::ProcessAction { source, .. } => match source.kind() {
+            io::ErrorKind::PermissionDenied => "process-error-permission-denied",
+            io::ErrorKind::NotFound => "process-error-not-found",
+            io::ErrorKind::Unsupported => "process-error-unsupported",
+            _ => "process-error-generic",
+        },
+        _ => "process-error-generic",
+    }
+}
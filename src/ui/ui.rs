@@ -1,27 +1,92 @@
 use crate::{
-    config::app_config::AppConfig,
-    enums::{monitor_type_enum::MonitorTypeEnum, supported_language_enum::SupportedLanguageEnum},
-    i18n::i18n_manager::I18nManager,
+    config::{
+        app_config::{AppConfig, ProcessGroupConfig, default_process_label},
+        config_validation,
+    },
+    enums::{
+        language_selection_enum::LanguageSelectionEnum, log_level_enum::LogLevelEnum,
+        monitor_type_enum::MonitorTypeEnum, process_filter_enum::ProcessFilterEnum,
+        process_metric_enum::ProcessMetricEnum,
+    },
+    error::lock_ext::LockExt,
+    i18n::i18n_manager::{I18nManager, LanguageChoices},
     ui::components,
 };
 use eframe::egui;
+use fluent::fluent_args;
 use std::sync::{Arc, Mutex};
 use strum::IntoEnumIterator;
 
+#[derive(Debug, Default)]
+pub struct DrawUiResult {
+    pub shutdown_requested: bool,
+    pub language_changed: bool,
+    pub autostart_changed: bool,
+    pub process_view_requested: bool,
+    pub log_level_changed: bool,
+    pub view_logs_requested: bool,
+}
+
+/// Inline form for adding a process group. The draft is kept in egui's temporary memory.
+fn draw_process_group_form(ui: &mut egui::Ui, i18n: &I18nManager) -> Option<ProcessGroupConfig> {
+    let id = ui.make_persistent_id("process_group_form");
+    let (mut pattern, mut match_command_line) = ui
+        .data_mut(|data| data.get_temp::<(String, bool)>(id))
+        .unwrap_or_default();
+    let mut added_group = None;
+
+    ui.horizontal(|ui| {
+        ui.add(
+            egui::TextEdit::singleline(&mut pattern)
+                .hint_text(i18n.get_message("process-group-pattern-hint"))
+                .desired_width(120.0),
+        );
+        ui.checkbox(
+            &mut match_command_line,
+            i18n.get_message("process-group-command-line-label"),
+        );
+
+        let validation = config_validation::check_pattern(&pattern);
+        let mut add_button = ui.add_enabled(validation.is_ok(), egui::Button::new("➕"));
+        if let Err(message) = &validation
+            && !pattern.is_empty()
+        {
+            add_button = add_button.on_disabled_hover_text(message);
+        }
+        if add_button.clicked() {
+            let filter = if match_command_line {
+                ProcessFilterEnum::CommandLinePattern(pattern.clone())
+            } else {
+                ProcessFilterEnum::NamePattern(pattern.clone())
+            };
+            added_group = Some(ProcessGroupConfig {
+                id: 0,
+                label: default_process_label(&pattern),
+                filter,
+                metric: ProcessMetricEnum::CpuUsage,
+                enabled: true,
+            });
+            pattern.clear();
+        }
+    });
+
+    ui.data_mut(|data| data.insert_temp(id, (pattern, match_command_line)));
+    added_group
+}
+
 pub fn draw_ui(
     ui: &mut egui::Ui,
     app_config: Arc<Mutex<AppConfig>>,
     i18n: Arc<Mutex<I18nManager>>,
-) -> (bool, bool, bool) {
-    let mut shutdown_requested = false;
-    let mut language_changed = false;
-    let mut autostart_setting_changed = false;
+    language_choices: &LanguageChoices,
+) -> DrawUiResult {
+    let mut result = DrawUiResult::default();
 
     egui::CentralPanel::default().show_inside(ui, |ui| {
         egui::Frame::group(ui.style()).show(ui, |ui| {
             ui.vertical_centered(|ui| {
                 ui.add_space(10.0);
-                let title = i18n.lock().unwrap().get_message("settings-title");
+                let title = i18n.lock_or_recover().get_message("settings-title");
                 ui.heading(title);
                 ui.add_space(5.0);
             });
@@ -35,13 +100,13 @@ pub fn draw_ui(
                 .spacing([40.0, 4.0])
                 .striped(true)
                 .show(ui, |ui| {
-                    let mut ac = app_config.lock().unwrap();
-                    let mut i18n_guard = i18n.lock().unwrap();
+                    let mut ac = app_config.lock_or_recover();
+                    let mut i18n_guard = i18n.lock_or_recover();
 
                     let label = i18n_guard.get_message("refresh-time-label");
                     ui.label(label);
                     let mut refresh_secs = ac.refresh.default_refresh_seconds;
-                    let range = ac.refresh.min_refresh_seconds..=ac.refresh.max_refresh_seconds;
+                    let range = config_validation::refresh_seconds_range(&ac.refresh);
                     if ui
                         .add(egui::Slider::new(&mut refresh_secs, range).text("s"))
                         .changed()
@@ -52,18 +117,51 @@ pub fn draw_ui(
 
                     let lang_label = i18n_guard.get_message("language-label");
                     ui.label(lang_label);
-                    let mut current_lang = ac.general.language;
+                    let mut current_lang = ac.general.language.clone();
+                    let current_name = i18n_guard.get_message("language-name");
+                    let selected_text = match current_lang {
+                        LanguageSelectionEnum::SystemDefault => i18n_guard.get_message_with_args(
+                            "language-system-default",
+                            &fluent_args!["language" => current_name],
+                        ),
+                        LanguageSelectionEnum::Language(_) => current_name,
+                    };
                     egui::ComboBox::from_id_salt("language_combo_box")
-                        .selected_text(current_lang.name())
+                        .selected_text(selected_text)
                         .show_ui(ui, |ui| {
-                            for lang in SupportedLanguageEnum::iter() {
+                            let options = std::iter::once((
+                                LanguageSelectionEnum::SystemDefault,
+                                i18n_guard.get_message_with_args(
+                                    "language-system-default",
+                                    &fluent_args![
+                                        "language" => language_choices.system_language_name.as_str()
+                                    ],
+                                ),
+                            ))
+                            .chain(
+                                language_choices.languages.iter().map(|lang| {
+                                    (
+                                        LanguageSelectionEnum::Language(lang.id.clone()),
+                                        lang.name.clone(),
+                                    )
+                                }),
+                            );
+
+                            for (selection, name) in options {
                                 if ui
-                                    .selectable_value(&mut current_lang, lang, lang.name())
+                                    .selectable_value(&mut current_lang, selection, name)
                                     .changed()
                                 {
-                                    ac.general.language = current_lang;
-                                    *i18n_guard = I18nManager::new(current_lang);
-                                    language_changed = true;
+                                    match I18nManager::new(&current_lang) {
+                                        Ok(i18n_manager) => {
+                                            ac.general.language = current_lang.clone();
+                                            *i18n_guard = i18n_manager;
+                                            result.language_changed = true;
+                                        }
+                                        Err(e) => {
+                                            tracing::warn!(error = %e, "Failed to switch language")
+                                        }
+                                    }
                                 }
                             }
                         });
@@ -78,7 +176,7 @@ pub fn draw_ui(
                         ))
                         .changed()
                     {
-                        autostart_setting_changed = true;
+                        result.autostart_changed = true;
                     }
                     ui.end_row();
 
@@ -90,42 +188,113 @@ pub fn draw_ui(
                     ));
                     ui.end_row();
 
-                    for monitor_type in MonitorTypeEnum::iter() {
-                        let label_key = monitor_type.icon_label_key();
+                    ui.label(i18n_guard.get_message("log-level-label"));
+                    ui.horizontal(|ui| {
+                        egui::ComboBox::from_id_salt("log_level_combo_box")
+                            .selected_text(i18n_guard.get_message(ac.logging.level.label_key()))
+                            .show_ui(ui, |ui| {
+                                for level in LogLevelEnum::iter() {
+                                    if ui
+                                        .selectable_value(
+                                            &mut ac.logging.level,
+                                            level,
+                                            i18n_guard.get_message(level.label_key()),
+                                        )
+                                        .changed()
+                                    {
+                                        result.log_level_changed = true;
+                                    }
+                                }
+                            });
+                        if ui
+                            .button(i18n_guard.get_message("view-logs-button"))
+                            .clicked()
+                        {
+                            result.view_logs_requested = true;
+                        }
+                    });
+                    ui.end_row();
+
+                    let mut removed_monitor = None;
+                    for monitor_type in ac.monitor_types() {
                         let label = format!(
                             "{} {}",
                             i18n_guard.get_message("monitor-label-prefix"),
-                            i18n_guard.get_message(label_key)
+                            monitor_type.icon_label(&i18n_guard, &ac)
                         );
                         ui.label(label);
-                        let mut is_active = ac.active_monitors.contains(&monitor_type);
-                        if ui
-                            .add(components::toggle_switch_component::toggle(&mut is_active))
-                            .changed()
-                        {
-                            if is_active {
-                                ac.active_monitors.insert(monitor_type);
-                            } else {
-                                ac.active_monitors.remove(&monitor_type);
+                        ui.horizontal(|ui| {
+                            let mut is_active = ac.is_monitor_active(monitor_type);
+                            if ui
+                                .add(components::toggle_switch_component::toggle(&mut is_active))
+                                .changed()
+                            {
+                                ac.set_monitor_active(monitor_type, is_active);
                             }
-                        }
+
+                            let process_entry = match monitor_type {
+                                MonitorTypeEnum::PinnedProcess(id) => ac
+                                    .pinned_process_mut(id)
+                                    .map(|pinned| (pinned.rule.to_string(), &mut pinned.metric)),
+                                MonitorTypeEnum::ProcessGroup(id) => ac
+                                    .process_group_mut(id)
+                                    .map(|group| (group.filter.to_string(), &mut group.metric)),
+                                _ => None,
+                            };
+
+                            if let Some((description, metric)) = process_entry {
+                                ui.label(description);
+                                egui::ComboBox::from_id_salt(("metric_combo_box", monitor_type))
+                                    .selected_text(i18n_guard.get_message(metric.label_key()))
+                                    .show_ui(ui, |ui| {
+                                        for option in ProcessMetricEnum::iter() {
+                                            ui.selectable_value(
+                                                metric,
+                                                option,
+                                                i18n_guard.get_message(option.label_key()),
+                                            );
+                                        }
+                                    });
+                                if ui
+                                    .small_button("🗑")
+                                    .on_hover_text(
+                                        i18n_guard.get_message("process-monitor-remove-tooltip"),
+                                    )
+                                    .clicked()
+                                {
+                                    removed_monitor = Some(monitor_type);
+                                }
+                            }
+                        });
                         ui.end_row();
                     }
+
+                    if let Some(monitor_type) = removed_monitor {
+                        ac.remove_monitor(monitor_type);
+                    }
+
+                    ui.label(i18n_guard.get_message("process-group-add-label"));
+                    if let Some(group) = draw_process_group_form(ui, &i18n_guard) {
+                        ac.add_process_group(group);
+                    }
+                    ui.end_row();
                 });
         });
 
         ui.with_layout(egui::Layout::bottom_up(egui::Align::Center), |ui| {
             ui.add_space(10.0);
-            let label = i18n.lock().unwrap().get_message("shutdown-button-label");
+            let label = i18n.lock_or_recover().get_message("shutdown-button-label");
+            if ui.button(label).clicked() {
+                result.shutdown_requested = true;
+            }
+
+            ui.add_space(5.0);
+            let label = i18n.lock_or_recover().get_message("processes-button-label");
             if ui.button(label).clicked() {
-                shutdown_requested = true;
+                result.process_view_requested = true;
             }
         });
     });
 
-    (
-        shutdown_requested,
-        language_changed,
-        autostart_setting_changed,
-    )
+    result
 }
@@ -0,0 +1,32 @@
+use eframe::egui;
+
+/// Shows a secondary window as an immediate viewport, falling back to an embedded
+/// `egui::Window` on backends without multi-viewport support.
+///
+/// Returns `true` when the user asked to close the window.
+pub fn show_child_viewport(
+    ctx: &egui::Context,
+    id_source: &str,
+    title: &str,
+    size: [f32; 2],
+    mut add_contents: impl FnMut(&mut egui::Ui),
+) -> bool {
+    ctx.show_viewport_immediate(
+        egui::ViewportId::from_hash_of(id_source),
+        egui::ViewportBuilder::default()
+            .with_title(title)
+            .with_inner_size(size),
+        |ctx, class| {
+            if class == egui::ViewportClass::Embedded {
+                let mut open = true;
+                egui::Window::new(title)
+                    .open(&mut open)
+                    .show(ctx, |ui| add_contents(ui));
+                !open
+            } else {
+                egui::CentralPanel::default().show(ctx, |ui| add_contents(ui));
+                ctx.input(|i| i.viewport().close_requested())
+            }
+        },
+    )
+}
@@ -1,19 +1,107 @@
 # === Main UI ===
-name = System monitor
 settings-title = System Monitor Settings
 refresh-time-label = Refresh time (seconds)
 shutdown-button-label = ✖ Shutdown application
 monitor-label-prefix = Show system monitor
+language-name = English
+language-system-default = System default ({ $language })
 language-label = Language
 run-on-startup-label = Run on startup
 minimized-on-startup-label = Start minimized
+log-level-label = Log level
+view-logs-button = View logs
+log-level-error = Errors
+log-level-warn = Warnings
+log-level-info = Information
+log-level-debug = Debug
+log-level-trace = Trace
 
 # === System Tray ===
 tray-settings-item = Settings
+tray-dashboard-item = Dashboard
+tray-monitors-submenu = Monitors
+tray-pause-item = Pause sampling
 tray-shutdown-item = Shutdown
 tray-tooltip-cpu-usage = CPU Usage Monitor
 tray-tooltip-ram-usage = RAM Usage Monitor
+tray-tooltip-pinned-process = Pinned Process Monitor
+tray-tooltip-process-group = Process Group Monitor
+tray-menu-value = { $label }: { $value }
+tray-menu-value-memory = { $label }: { $value } ({ $used }/{ $total } GiB)
+tray-menu-value-process-group = { $label }: { $value } (×{ $count })
+tray-tooltip-value = { $title }: { $value }
+tray-tooltip-memory = Used { $used } of { $total } GiB
+tray-tooltip-process-group-details = CPU { $cpu } · Memory { $memory } · { $count ->
+    [one] { $count } process
+   *[other] { $count } processes
+}
+tray-tooltip-statistics = Last { $minutes } min: min { $min } · avg { $avg } · max { $max }
+
+# === Dashboard ===
+dashboard-window-title = Dashboard
+dashboard-time-window-label = Time window:
+dashboard-window-1m = 1 min
+dashboard-window-10m = 10 min
+dashboard-window-1h = 1 h
+dashboard-point-label = { $value }
+    { $seconds } s ago
+dashboard-no-data = Waiting for data…
+
+# === Units ===
+unit-percent = { $value }%
+unit-kilobytes = { $value } KiB
+unit-megabytes = { $value } MiB
+unit-gigabytes = { $value } GiB
+icon-value-kilobytes = { $value }K
+icon-value-megabytes = { $value }M
+icon-value-gigabytes = { $value }G
 
 # === Icon Labels ===
 icon-label-cpu-usage = CPU
 icon-label-ram-usage = RAM
+icon-label-pinned-process = PRC
+icon-label-process-group = GRP
+
+# === Processes ===
+processes-button-label = Processes
+processes-window-title = Processes
+process-column-pid = PID
+process-column-name = Name
+process-column-cpu = CPU %
+process-column-memory = Memory
+process-action-terminate = Terminate
+process-action-kill = Kill
+process-action-renice = Change priority…
+process-confirm-title = Confirm action
+process-confirm-terminate = Ask this process to terminate?
+process-confirm-kill = Forcefully kill this process? Unsaved data will be lost.
+process-confirm-renice = Change the priority (nice value) of this process?
+process-nice-label = Nice value
+process-confirm-button = Confirm
+process-cancel-button = Cancel
+process-error-permission-denied = Permission denied. The process belongs to another user or requires elevated privileges.
+process-error-not-found = The process no longer exists.
+process-error-unsupported = This action is not supported on this system.
+process-error-generic = The action on the process failed.
+process-pin-by-name = Pin as monitor (by name)
+process-pin-by-pid = Pin as monitor (by PID)
+process-metric-cpu-usage = CPU usage
+process-metric-memory = Memory (RSS)
+process-group-by-name = Monitor all processes with this name
+process-monitor-remove-tooltip = Remove monitor
+process-group-add-label = Add process group
+process-group-pattern-hint = Regular expression
+process-group-command-line-label = Match command line
+
+# === Startup errors ===
+startup-error-title = Rust System Monitor could not start
+startup-error-close-button = Close
+config-error-message = The configuration file { $path } could not be read. Fix or remove it and start the application again.
+startup-error-message = An unexpected error occurred while starting. The details below may help to find the cause.
+
+# === Configuration file ===
+config-conflict-message = The configuration file was changed outside the application while you have unsaved changes.
+config-conflict-reload-button = Load changes from file
+config-conflict-keep-button = Keep my changes
+config-reload-error = Changes to the configuration file were ignored: { $details }
+config-save-error = Settings could not be saved: { $details }
@@ -1,19 +1,109 @@
 # === Główne UI ===
-name = Monitor systemu
 settings-title = Ustawienia Monitora Systemu
 refresh-time-label = Czas odświeżania (sekundy)
 shutdown-button-label = ✖ Zamknij aplikację
 monitor-label-prefix = Pokaż monitor systemu
+language-name = Polski
+language-system-default = Język systemu ({ $language })
 language-label = Język
 run-on-startup-label = Uruchom aplikację przy starcie systemu
 minimized-on-startup-label = Uruchom zminimalizowany
+log-level-label = Poziom logowania
+view-logs-button = Pokaż logi
+log-level-error = Błędy
+log-level-warn = Ostrzeżenia
+log-level-info = Informacje
+log-level-debug = Debugowanie
+log-level-trace = Śledzenie
 
 # === Zasobnik systemowy (Tray) ===
 tray-settings-item = Ustawienia
+tray-dashboard-item = Panel wykresów
+tray-monitors-submenu = Monitory
+tray-pause-item = Wstrzymaj próbkowanie
 tray-shutdown-item = Zamknij
 tray-tooltip-cpu-usage = Monitor Użycia CPU
 tray-tooltip-ram-usage = Monitor Użycia RAM
+tray-tooltip-pinned-process = Monitor Przypiętego Procesu
+tray-tooltip-process-group = Monitor Grupy Procesów
+tray-menu-value = { $label }: { $value }
+tray-menu-value-memory = { $label }: { $value } ({ $used }/{ $total } GiB)
+tray-menu-value-process-group = { $label }: { $value } (×{ $count })
+tray-tooltip-value = { $title }: { $value }
+tray-tooltip-memory = Użyto { $used } z { $total } GiB
+tray-tooltip-process-group-details = CPU { $cpu } · Pamięć { $memory } · { $count ->
+    [one] { $count } proces
+    [few] { $count } procesy
+    [many] { $count } procesów
+   *[other] { $count } procesu
+}
+tray-tooltip-statistics = Ostatnie { $minutes } min: min { $min } · śr. { $avg } · maks. { $max }
+
+# === Panel wykresów ===
+dashboard-window-title = Panel wykresów
+dashboard-time-window-label = Zakres czasu:
+dashboard-window-1m = 1 min
+dashboard-window-10m = 10 min
+dashboard-window-1h = 1 godz.
+dashboard-point-label = { $value }
+    { $seconds } s temu
+dashboard-no-data = Oczekiwanie na dane…
+
+# === Jednostki ===
+unit-percent = { $value }%
+unit-kilobytes = { $value } KiB
+unit-megabytes = { $value } MiB
+unit-gigabytes = { $value } GiB
+icon-value-kilobytes = { $value }K
+icon-value-megabytes = { $value }M
+icon-value-gigabytes = { $value }G
 
 # === Etykiety dla ikon ===
 icon-label-cpu-usage = CPU
 icon-label-ram-usage = RAM
+icon-label-pinned-process = PRC
+icon-label-process-group = GRP
+
+# === Procesy ===
+processes-button-label = Procesy
+processes-window-title = Procesy
+process-column-pid = PID
+process-column-name = Nazwa
+process-column-cpu = CPU %
+process-column-memory = Pamięć
+process-action-terminate = Zakończ
+process-action-kill = Zabij
+process-action-renice = Zmień priorytet…
+process-confirm-title = Potwierdź akcję
+process-confirm-terminate = Poprosić ten proces o zakończenie?
+process-confirm-kill = Wymusić zabicie tego procesu? Niezapisane dane zostaną utracone.
+process-confirm-renice = Zmienić priorytet (wartość nice) tego procesu?
+process-nice-label = Wartość nice
+process-confirm-button = Potwierdź
+process-cancel-button = Anuluj
+process-error-permission-denied = Brak uprawnień. Proces należy do innego użytkownika lub wymaga podwyższonych uprawnień.
+process-error-not-found = Proces już nie istnieje.
+process-error-unsupported = Ta akcja nie jest obsługiwana w tym systemie.
+process-error-generic = Akcja na procesie nie powiodła się.
+process-pin-by-name = Przypnij jako monitor (po nazwie)
+process-pin-by-pid = Przypnij jako monitor (po PID)
+process-metric-cpu-usage = Użycie CPU
+process-metric-memory = Pamięć (RSS)
+process-group-by-name = Monitoruj wszystkie procesy o tej nazwie
+process-monitor-remove-tooltip = Usuń monitor
+process-group-add-label = Dodaj grupę procesów
+process-group-pattern-hint = Wyrażenie regularne
+process-group-command-line-label = Dopasuj linię poleceń
+
+# === Błędy uruchamiania ===
+startup-error-title = Nie udało się uruchomić Rust System Monitor
+startup-error-close-button = Zamknij
+config-error-message = Nie można odczytać pliku konfiguracyjnego { $path }. Popraw go lub usuń i uruchom aplikację ponownie.
+startup-error-message = Podczas uruchamiania wystąpił nieoczekiwany błąd. Poniższe szczegóły mogą pomóc ustalić przyczynę.
+
+# === Plik konfiguracyjny ===
+config-conflict-message = Plik konfiguracyjny został zmieniony poza aplikacją, a masz niezapisane zmiany.
+config-conflict-reload-button = Wczytaj zmiany z pliku
+config-conflict-keep-button = Zachowaj moje zmiany
+config-reload-error = Zmiany w pliku konfiguracyjnym zostały zignorowane: { $details }
+config-save-error = Nie udało się zapisać ustawień: { $details }